@@ -1,18 +1,21 @@
-use std::ops::{BitAnd, BitOr, BitXor, Not};
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
 
-use bitflags::{Bits, Flag, Flags};
+use bitflags::{parser::WriteHex, Bits, Flag, Flags};
 
 // Define a custom container that can be used in flags types
 // Note custom bits types can't be used in `bitflags!`
 // without making the trait impls `const`. This is currently
 // unstable
 #[derive(Clone, Copy, Debug)]
-pub struct CustomBits([bool; 3]);
+pub struct CustomBits([bool; 4]);
 
 impl Bits for CustomBits {
-    const EMPTY: Self = CustomBits([false; 3]);
+    const EMPTY: Self = CustomBits([false; 4]);
 
-    const ALL: Self = CustomBits([true; 3]);
+    const ALL: Self = CustomBits([true; 4]);
 }
 
 impl PartialEq for CustomBits {
@@ -29,6 +32,7 @@ impl BitAnd for CustomBits {
             self.0[0] & other.0[0],
             self.0[1] & other.0[1],
             self.0[2] & other.0[2],
+            self.0[3] & other.0[3],
         ])
     }
 }
@@ -41,6 +45,7 @@ impl BitOr for CustomBits {
             self.0[0] | other.0[0],
             self.0[1] | other.0[1],
             self.0[2] | other.0[2],
+            self.0[3] | other.0[3],
         ])
     }
 }
@@ -53,6 +58,7 @@ impl BitXor for CustomBits {
             self.0[0] & other.0[0],
             self.0[1] & other.0[1],
             self.0[2] & other.0[2],
+            self.0[3] & other.0[3],
         ])
     }
 }
@@ -61,7 +67,21 @@ impl Not for CustomBits {
     type Output = Self;
 
     fn not(self) -> Self {
-        CustomBits([!self.0[0], !self.0[1], !self.0[2]])
+        CustomBits([!self.0[0], !self.0[1], !self.0[2], !self.0[3]])
+    }
+}
+
+// Formatting flags values as text needs a way to write any bits
+// that don't correspond to a flag as hex
+impl WriteHex for CustomBits {
+    fn write_hex<W: fmt::Write>(&self, mut writer: W) -> fmt::Result {
+        let bits = self
+            .0
+            .iter()
+            .rev()
+            .fold(0u8, |bits, bit| bits << 1 | *bit as u8);
+
+        write!(writer, "{:x}", bits)
     }
 }
 
@@ -69,9 +89,9 @@ impl Not for CustomBits {
 pub struct CustomFlags(CustomBits);
 
 impl CustomFlags {
-    pub const A: Self = CustomFlags(CustomBits([true, false, false]));
-    pub const B: Self = CustomFlags(CustomBits([false, true, false]));
-    pub const C: Self = CustomFlags(CustomBits([false, false, true]));
+    pub const A: Self = CustomFlags(CustomBits([true, false, false, false]));
+    pub const B: Self = CustomFlags(CustomBits([false, true, false, false]));
+    pub const C: Self = CustomFlags(CustomBits([false, false, true, false]));
 }
 
 impl Flags for CustomFlags {
@@ -94,4 +114,17 @@ impl Flags for CustomFlags {
 
 fn main() {
     println!("{:?}", CustomFlags::A.union(CustomFlags::C));
+
+    let mut formatted = String::new();
+    bitflags::parser::to_writer(&CustomFlags::A.union(CustomFlags::C), &mut formatted).unwrap();
+    println!("{}", formatted);
+
+    // The last bit doesn't correspond to a flag, so it's formatted as hex using `WriteHex`
+    let unnamed = CustomFlags::A.union(CustomFlags::from_bits_retain(CustomBits([
+        false, false, false, true,
+    ])));
+
+    let mut formatted = String::new();
+    bitflags::parser::to_writer(&unnamed, &mut formatted).unwrap();
+    println!("{}", formatted);
 }
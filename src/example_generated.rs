@@ -4,6 +4,8 @@
 //! Usually, when you call the `bitflags!` macro, only the `Flags` type would be visible. In this
 //! example, the `Field0`, `Iter`, and `IterRaw` types are also exposed so that you can explore
 //! their APIs. The `Field0` type can be accessed as `self.0` on an instance of `Flags`.
+//!
+//! The `DocTableFlags` type shows the table of flags generated by `#[bitflags(doc_table)]`.
 
 __declare_public_bitflags! {
     /// This is the same `Flags` struct defined in the [crate level example](../index.html#example).
@@ -17,6 +19,8 @@ __declare_internal_bitflags! {
 }
 
 __impl_internal_bitflags! {
+    inline: inline,
+    debug: names,
     Field0: u32, Flags {
         // Field `A`.
         ///
@@ -35,6 +39,7 @@ __impl_internal_bitflags! {
 }
 
 __impl_public_bitflags_forward! {
+    inline: inline,
     Flags: u32, Field0
 }
 
@@ -43,6 +48,7 @@ __impl_public_bitflags_ops! {
 }
 
 __impl_public_bitflags_iter! {
+    inline: inline,
     Flags: u32, Flags
 }
 
@@ -63,3 +69,59 @@ __impl_public_bitflags_consts! {
         const ABC = Self::A.bits() | Self::B.bits() | Self::C.bits();
     }
 }
+
+__declare_public_bitflags! {
+    /// An example of a flags type using `#[bitflags(doc_table)]`.
+    ///
+    /// The table below is generated from the definitions and doc comments of its flags.
+    #[doc = __bitflags_doc_table! {
+        /// The first flag.
+        const A = 0b00000001;
+        /// The second flag.
+        ///
+        /// Its documentation spans multiple paragraphs.
+        const B = 0b00000010;
+        const AB = Self::A.bits() | Self::B.bits();
+    }]
+    pub struct DocTableFlags
+}
+
+__declare_internal_bitflags! {
+    pub struct DocTableField0: u32
+}
+
+__impl_internal_bitflags! {
+    inline: inline,
+    debug: names,
+    DocTableField0: u32, DocTableFlags {
+        const A = 0b00000001;
+        const B = 0b00000010;
+        const AB = Self::A.bits() | Self::B.bits();
+    }
+}
+
+__impl_public_bitflags_forward! {
+    inline: inline,
+    DocTableFlags: u32, DocTableField0
+}
+
+__impl_public_bitflags_ops! {
+    DocTableFlags
+}
+
+__impl_public_bitflags_iter! {
+    inline: inline,
+    DocTableFlags: u32, DocTableFlags
+}
+
+__impl_public_bitflags_consts! {
+    DocTableFlags: u32 {
+        /// The first flag.
+        const A = 0b00000001;
+        /// The second flag.
+        ///
+        /// Its documentation spans multiple paragraphs.
+        const B = 0b00000010;
+        const AB = Self::A.bits() | Self::B.bits();
+    }
+}
@@ -74,6 +74,33 @@ pub(crate) mod __private {
 
     #[cfg(feature = "bytemuck")]
     pub use bytemuck;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+
+    #[cfg(feature = "quickcheck")]
+    pub use quickcheck;
+
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "speedy")]
+    pub use speedy;
+
+    #[cfg(feature = "databake")]
+    pub use databake;
+
+    #[cfg(any(feature = "schemars", feature = "quickcheck"))]
+    pub extern crate alloc;
+
+    #[cfg(feature = "schemars")]
+    pub use schemars;
 }
 
 /// Implements traits from external libraries for the internal bitflags type.
@@ -81,6 +108,7 @@ pub(crate) mod __private {
 #[doc(hidden)]
 macro_rules! __impl_external_bitflags {
     (
+        databake: [$($databake:tt)*],
         $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
             $(
                 $(#[$inner:ident $($args:tt)*])*
@@ -118,6 +146,74 @@ macro_rules! __impl_external_bitflags {
                 )*
             }
         }
+
+        $crate::__impl_external_bitflags_defmt! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        $crate::__impl_external_bitflags_proptest! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        $crate::__impl_external_bitflags_quickcheck! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        $crate::__impl_external_bitflags_rkyv! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        $crate::__impl_external_bitflags_borsh! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        $crate::__impl_external_bitflags_speedy! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        $crate::__impl_external_bitflags_schemars! {
+            $InternalBitFlags: $T, $PublicBitFlags {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag;
+                )*
+            }
+        }
+
+        $crate::__impl_external_bitflags_databake! {
+            databake: [$($databake)*],
+            $PublicBitFlags
+        }
     };
 }
 
@@ -178,6 +274,12 @@ macro_rules! __impl_external_bitflags_serde {
 #[cfg(feature = "arbitrary")]
 pub mod arbitrary;
 
+#[cfg(feature = "rand")]
+pub mod rand;
+
+#[cfg(feature = "ethnum")]
+mod ethnum;
+
 #[cfg(feature = "bytemuck")]
 mod bytemuck;
 
@@ -219,6 +321,10 @@ macro_rules! __impl_external_bitflags_arbitrary {
 }
 
 /// Implement `Pod` and `Zeroable` for the internal bitflags type.
+///
+/// `CheckedBitPattern` isn't implemented here, because `bytemuck` already implements it for
+/// any `Pod` type, accepting any bit pattern. Flags types that need to reject unknown bits can
+/// skip deriving `Pod` and implement `CheckedBitPattern` themselves using `from_bits`.
 #[macro_export]
 #[doc(hidden)]
 #[cfg(feature = "bytemuck")]
@@ -260,3 +366,471 @@ macro_rules! __impl_external_bitflags_bytemuck {
         }
     ) => {};
 }
+
+#[cfg(feature = "defmt")]
+pub mod defmt;
+
+/// Implement `Format` for the internal bitflags type.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "defmt")]
+macro_rules! __impl_external_bitflags_defmt {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::defmt::Format for $InternalBitFlags {
+            fn format(&self, f: $crate::__private::defmt::Formatter) {
+                $crate::defmt::format(&$PublicBitFlags::from_bits_retain(self.bits()), f)
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "defmt"))]
+macro_rules! __impl_external_bitflags_defmt {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+/// Implement `proptest::arbitrary::Arbitrary` for the internal bitflags type.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "proptest")]
+macro_rules! __impl_external_bitflags_proptest {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::proptest::arbitrary::Arbitrary for $InternalBitFlags {
+            type Parameters = <$T as $crate::__private::proptest::arbitrary::Arbitrary>::Parameters;
+            type Strategy = $crate::__private::proptest::strategy::Map<
+                <$T as $crate::__private::proptest::arbitrary::Arbitrary>::Strategy,
+                fn($T) -> Self,
+            >;
+
+            fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+                $crate::__private::proptest::strategy::Strategy::prop_map(
+                    <$T as $crate::__private::proptest::arbitrary::Arbitrary>::arbitrary_with(args),
+                    Self::from_bits_truncate,
+                )
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "proptest"))]
+macro_rules! __impl_external_bitflags_proptest {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+
+/// Implement `quickcheck::Arbitrary` for the internal bitflags type.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "quickcheck")]
+macro_rules! __impl_external_bitflags_quickcheck {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::quickcheck::Arbitrary for $InternalBitFlags {
+            fn arbitrary(g: &mut $crate::__private::quickcheck::Gen) -> Self {
+                $crate::quickcheck::arbitrary::<$PublicBitFlags>(g).0
+            }
+
+            fn shrink(
+                &self,
+            ) -> $crate::__private::alloc::boxed::Box<
+                dyn $crate::__private::core::iter::Iterator<Item = Self>,
+            > {
+                $crate::__private::alloc::boxed::Box::new(
+                    $crate::quickcheck::shrink(&$PublicBitFlags::from_bits_retain(self.bits()))
+                        .map(|flags| flags.0),
+                )
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "quickcheck"))]
+macro_rules! __impl_external_bitflags_quickcheck {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+/// Implement `Archive`, `Serialize`, and `Deserialize` for the internal bitflags type.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "rkyv")]
+macro_rules! __impl_external_bitflags_rkyv {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        /// The archived form of the internal bitflags type.
+        #[repr(transparent)]
+        pub struct ArchivedInternalBitFlags($crate::__private::rkyv::Archived<$T>);
+
+        // SAFETY: ArchivedInternalBitFlags is guaranteed to have the same ABI as
+        // the archived $T, which implements Portable
+        unsafe impl $crate::__private::rkyv::Portable for ArchivedInternalBitFlags where
+            $crate::__private::rkyv::Archived<$T>: $crate::__private::rkyv::Portable
+        {
+        }
+
+        // SAFETY: ArchivedInternalBitFlags is guaranteed to have the same ABI as
+        // the archived $T, so it's valid whenever the archived $T is
+        unsafe impl<C: $crate::__private::rkyv::rancor::Fallible + ?Sized>
+            $crate::__private::rkyv::bytecheck::CheckBytes<C> for ArchivedInternalBitFlags
+        where
+            $crate::__private::rkyv::Archived<$T>: $crate::__private::rkyv::bytecheck::CheckBytes<C>,
+        {
+            unsafe fn check_bytes(
+                value: *const Self,
+                context: &mut C,
+            ) -> $crate::__private::core::result::Result<(), C::Error> {
+                <$crate::__private::rkyv::Archived<$T> as $crate::__private::rkyv::bytecheck::CheckBytes<C>>::check_bytes(
+                    value.cast(),
+                    context,
+                )
+            }
+        }
+
+        impl $crate::__private::rkyv::Archive for $InternalBitFlags {
+            type Archived = ArchivedInternalBitFlags;
+            type Resolver = $crate::__private::rkyv::Resolver<$T>;
+
+            fn resolve(
+                &self,
+                resolver: Self::Resolver,
+                out: $crate::__private::rkyv::Place<Self::Archived>,
+            ) {
+                // SAFETY: ArchivedInternalBitFlags is guaranteed to have the same ABI as
+                // the archived $T
+                let out = unsafe { out.cast_unchecked() };
+
+                <$T as $crate::__private::rkyv::Archive>::resolve(&self.bits(), resolver, out)
+            }
+        }
+
+        impl<S: $crate::__private::rkyv::rancor::Fallible + ?Sized>
+            $crate::__private::rkyv::Serialize<S> for $InternalBitFlags
+        where
+            $T: $crate::__private::rkyv::Serialize<S>,
+        {
+            fn serialize(
+                &self,
+                serializer: &mut S,
+            ) -> $crate::__private::core::result::Result<Self::Resolver, S::Error> {
+                <$T as $crate::__private::rkyv::Serialize<S>>::serialize(&self.bits(), serializer)
+            }
+        }
+
+        impl<D: $crate::__private::rkyv::rancor::Fallible + ?Sized>
+            $crate::__private::rkyv::Deserialize<$InternalBitFlags, D> for ArchivedInternalBitFlags
+        where
+            $crate::__private::rkyv::Archived<$T>: $crate::__private::rkyv::Deserialize<$T, D>,
+        {
+            fn deserialize(
+                &self,
+                deserializer: &mut D,
+            ) -> $crate::__private::core::result::Result<$InternalBitFlags, D::Error> {
+                let bits = $crate::__private::rkyv::Deserialize::<$T, D>::deserialize(
+                    &self.0,
+                    deserializer,
+                )?;
+
+                $crate::__private::core::result::Result::Ok($InternalBitFlags::from_bits_retain(bits))
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "rkyv"))]
+macro_rules! __impl_external_bitflags_rkyv {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+/// Implement `BorshSerialize` and `BorshDeserialize` for the internal bitflags type.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "borsh")]
+macro_rules! __impl_external_bitflags_borsh {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::borsh::BorshSerialize for $InternalBitFlags {
+            fn serialize<W: $crate::__private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::__private::borsh::io::Result<()> {
+                $crate::__private::borsh::BorshSerialize::serialize(&self.bits(), writer)
+            }
+        }
+
+        impl $crate::__private::borsh::BorshDeserialize for $InternalBitFlags {
+            fn deserialize_reader<R: $crate::__private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::__private::borsh::io::Result<Self> {
+                let bits: $T =
+                    $crate::__private::borsh::BorshDeserialize::deserialize_reader(reader)?;
+
+                $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "borsh"))]
+macro_rules! __impl_external_bitflags_borsh {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "speedy")]
+mod speedy;
+
+/// Implement `Readable` and `Writable` for the internal bitflags type.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "speedy")]
+macro_rules! __impl_external_bitflags_speedy {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl<'a, C: $crate::__private::speedy::Context> $crate::__private::speedy::Readable<'a, C>
+            for $InternalBitFlags
+        where
+            $T: $crate::__private::speedy::Readable<'a, C>,
+        {
+            fn read_from<R: $crate::__private::speedy::Reader<'a, C>>(
+                reader: &mut R,
+            ) -> $crate::__private::core::result::Result<Self, C::Error> {
+                let bits: $T = $crate::__private::speedy::Readable::read_from(reader)?;
+
+                $crate::__private::core::result::Result::Ok(Self::from_bits_retain(bits))
+            }
+
+            fn minimum_bytes_needed() -> usize {
+                <$T as $crate::__private::speedy::Readable<'a, C>>::minimum_bytes_needed()
+            }
+        }
+
+        impl<C: $crate::__private::speedy::Context> $crate::__private::speedy::Writable<C>
+            for $InternalBitFlags
+        where
+            $T: $crate::__private::speedy::Writable<C>,
+        {
+            fn write_to<W: ?Sized + $crate::__private::speedy::Writer<C>>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::__private::core::result::Result<(), C::Error> {
+                $crate::__private::speedy::Writable::write_to(&self.bits(), writer)
+            }
+
+            fn bytes_needed(&self) -> $crate::__private::core::result::Result<usize, C::Error> {
+                $crate::__private::speedy::Writable::<C>::bytes_needed(&self.bits())
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "speedy"))]
+macro_rules! __impl_external_bitflags_speedy {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "schemars")]
+pub mod schemars;
+
+/// Implement `JsonSchema` for the internal bitflags type.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "schemars")]
+macro_rules! __impl_external_bitflags_schemars {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {
+        impl $crate::__private::schemars::JsonSchema for $InternalBitFlags {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> $crate::__private::alloc::borrow::Cow<'static, str> {
+                $crate::__private::alloc::borrow::Cow::Borrowed(
+                    $crate::__private::core::stringify!($PublicBitFlags),
+                )
+            }
+
+            fn json_schema(
+                _: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::Schema {
+                $crate::schemars::json_schema::<$PublicBitFlags>()
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "schemars"))]
+macro_rules! __impl_external_bitflags_schemars {
+    (
+        $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt;
+            )*
+        }
+    ) => {};
+}
+
+#[cfg(feature = "databake")]
+pub mod databake;
+
+/// Implement `Bake` and `BakeSize` for the public bitflags type.
+///
+/// Unlike other libraries, these traits are implemented for the public type, because the internal
+/// type can't be named in baked code. They're only implemented for flags types with a
+/// `#[bitflags(databake_path = "..")]` attribute, which gives the path of the module to bake the
+/// flags type with.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "databake")]
+macro_rules! __impl_external_bitflags_databake {
+    (
+        databake: [],
+        $PublicBitFlags:ident
+    ) => {};
+    (
+        databake: [$path:literal],
+        $PublicBitFlags:ident
+    ) => {
+        impl $crate::__private::databake::Bake for $PublicBitFlags {
+            fn bake(
+                &self,
+                ctx: &$crate::__private::databake::CrateEnv,
+            ) -> $crate::__private::databake::TokenStream {
+                $crate::databake::bake(
+                    self,
+                    $crate::__private::core::concat!(
+                        $path,
+                        "::",
+                        $crate::__private::core::stringify!($PublicBitFlags)
+                    ),
+                    ctx,
+                )
+            }
+        }
+
+        impl $crate::__private::databake::BakeSize for $PublicBitFlags {
+            fn borrows_size(&self) -> usize {
+                0
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "databake"))]
+macro_rules! __impl_external_bitflags_databake {
+    (
+        databake: [$($databake:tt)*],
+        $PublicBitFlags:ident
+    ) => {};
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use bytemuck::{Pod, Zeroable};
+    use bytemuck::{checked::CheckedBitPattern, Pod, Zeroable};
 
     bitflags! {
         #[derive(Pod, Zeroable, Clone, Copy)]
@@ -12,8 +12,45 @@ mod tests {
         }
     }
 
+    bitflags! {
+        #[derive(Zeroable, Clone, Copy, Debug, PartialEq, Eq)]
+        #[repr(transparent)]
+        struct CheckedColor: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    // SAFETY: CheckedColor is a transparent wrapper around `u32`, and only bit patterns
+    // that are valid for `u32` are accepted
+    unsafe impl CheckedBitPattern for CheckedColor {
+        type Bits = u32;
+
+        fn is_valid_bit_pattern(bits: &u32) -> bool {
+            CheckedColor::from_bits(*bits).is_some()
+        }
+    }
+
     #[test]
     fn test_bytemuck() {
         assert_eq!(0x1, bytemuck::cast::<Color, u32>(Color::RED));
     }
+
+    #[test]
+    fn test_bytemuck_pod_unknown_bits() {
+        // `Pod` flags types accept any bit pattern, including unknown bits
+        assert_eq!(0x8, bytemuck::cast::<u32, Color>(0x8).bits());
+    }
+
+    #[test]
+    fn test_bytemuck_checked() {
+        assert_eq!(
+            Ok(CheckedColor::RED | CheckedColor::BLUE),
+            bytemuck::checked::try_cast::<u32, CheckedColor>(0x1 | 0x4)
+        );
+
+        assert!(bytemuck::checked::try_cast::<u32, CheckedColor>(0x8).is_err());
+        assert!(bytemuck::checked::try_cast::<u32, CheckedColor>(0x1 | 0x8).is_err());
+    }
 }
@@ -0,0 +1,130 @@
+/*!
+Baking flags values into Rust code using `databake`.
+
+Flags values are baked as a call to `from_bits_retain` on the flags type, so any unknown bits
+are preserved and the baked value is equal to the original one. Add a
+`#[bitflags(databake_path = "..")]` attribute with the path of the module the flags type is
+reachable from to implement `Bake` and `BakeSize` for it:
+
+```
+use bitflags::bitflags;
+use databake::{Bake, CrateEnv};
+
+bitflags! {
+    #[bitflags(databake_path = "my_crate::flags")]
+    pub struct Flags: u32 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+assert_eq!(
+    "my_crate :: flags :: Flags :: from_bits_retain (3u32)",
+    (Flags::A | Flags::B).bake(&CrateEnv::default()).to_string(),
+);
+```
+
+Flags types without the attribute don't implement `Bake`, so it can be implemented manually
+with [`bake`] instead.
+*/
+
+use databake::{quote, Bake, CrateEnv, TokenStream};
+
+use crate::Flags;
+
+/**
+Bake a flags value, using the path to its flags type.
+
+The first segment of `path` is the crate the flags type is reachable from, so it's
+added to the `ctx`.
+*/
+pub fn bake<B: Flags>(flags: &B, path: &'static str, ctx: &CrateEnv) -> TokenStream
+where
+    B::Bits: Bake,
+{
+    if let Some(krate) = path.split("::").next() {
+        ctx.insert(krate);
+    }
+
+    let path: TokenStream = path.parse().expect("a type path is a valid path");
+    let bits = flags.bits().bake(ctx);
+
+    quote! {
+        #path::from_bits_retain(#bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databake::{test_bake, Bake, BakeSize, CrateEnv, TokenStream};
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[bitflags(databake_path = "bitflags::external::databake::tests")]
+        pub(crate) struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+
+        #[derive(Debug, PartialEq, Eq)]
+        pub(crate) struct Manual: u8 {
+            const A = 0x1;
+        }
+    }
+
+    // Flags types without a `databake_path` can implement `Bake` themselves
+    impl Bake for Manual {
+        fn bake(&self, ctx: &CrateEnv) -> TokenStream {
+            super::bake(self, "bitflags::external::databake::tests::Manual", ctx)
+        }
+    }
+
+    impl BakeSize for Manual {
+        fn borrows_size(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn test_bake() {
+        test_bake!(
+            Color,
+            const,
+            crate::external::databake::tests::Color::from_bits_retain(5u32),
+            bitflags
+        );
+
+        // Unknown bits are preserved
+        test_bake!(
+            Color,
+            const,
+            crate::external::databake::tests::Color::from_bits_retain(9u32),
+            bitflags
+        );
+
+        test_bake!(
+            Manual,
+            const,
+            crate::external::databake::tests::Manual::from_bits_retain(1u8),
+            bitflags
+        );
+    }
+
+    #[test]
+    fn test_bake_roundtrip() {
+        let ctx = CrateEnv::default();
+
+        let flags = Color::RED | Color::from_bits_retain(1 << 8);
+        assert_eq!(
+            "bitflags :: external :: databake :: tests :: Color :: from_bits_retain (257u32)",
+            flags.bake(&ctx).to_string()
+        );
+
+        assert_eq!(
+            flags,
+            crate::external::databake::tests::Color::from_bits_retain(257u32)
+        );
+        assert_eq!(0, flags.borrows_size());
+    }
+}
@@ -0,0 +1,56 @@
+//! Specialized formatting for flags types using `defmt`.
+
+use crate::{Bits, Flags};
+
+/**
+Format a flags value using `defmt`.
+
+The output matches [`crate::parser::to_writer`], like `A | B | 0xf6`.
+*/
+pub fn format<B: Flags>(flags: &B, f: defmt::Formatter)
+where
+    B::Bits: defmt::Format,
+{
+    // Iterate over known flag values
+    let mut first = true;
+    let mut iter = flags.iter_names();
+    for (name, _) in &mut iter {
+        if !first {
+            defmt::write!(f, " | ");
+        }
+
+        first = false;
+        defmt::write!(f, "{=str}", name);
+    }
+
+    // Append any extra bits that correspond to flags to the end of the format
+    let remaining = iter.remaining().bits();
+    if remaining != B::Bits::EMPTY {
+        if !first {
+            defmt::write!(f, " | ");
+        }
+
+        defmt::write!(f, "{:#x}", remaining);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    bitflags! {
+        #[derive(defmt::Format)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn test_defmt() {
+        // Actually writing through `defmt` needs a global logger and linker support
+        // that's only available on embedded targets
+        assert_format::<Color>();
+    }
+}
@@ -0,0 +1,158 @@
+//! Support for 256-bit flags types using `ethnum`.
+//!
+//! The `bitflags!` macro only accepts primitive integers, so flags types backed by
+//! [`ethnum::U256`] need to implement [`Flags`](crate::Flags) manually.
+
+use core::fmt;
+
+use ethnum::U256;
+
+use crate::{
+    iter::BitPositions,
+    parser::{ParseError, ParseHex, WriteHex},
+    traits::strip_hex_separators,
+    Bits,
+};
+
+// The number of hex digits needed for a 256-bit number
+const HEX_DIGITS: usize = 64;
+
+impl Bits for U256 {
+    const EMPTY: U256 = U256::ZERO;
+    const ALL: U256 = U256::MAX;
+}
+
+impl ParseHex for U256 {
+    fn parse_hex(input: &str) -> Result<Self, ParseError> {
+        let mut buf = [0; HEX_DIGITS];
+        let digits = strip_hex_separators(input, &mut buf)
+            .ok_or_else(|| ParseError::invalid_hex_flag(input))?;
+
+        U256::from_str_radix(digits, 16).map_err(|_| ParseError::invalid_hex_flag(input))
+    }
+
+    fn parse_bin(input: &str) -> Result<Self, ParseError> {
+        U256::from_str_radix(input, 2).map_err(|_| ParseError::invalid_bin_flag(input))
+    }
+
+    fn parse_oct(input: &str) -> Result<Self, ParseError> {
+        U256::from_str_radix(input, 8).map_err(|_| ParseError::invalid_oct_flag(input))
+    }
+}
+
+impl WriteHex for U256 {
+    fn write_hex<W: fmt::Write>(&self, mut writer: W) -> fmt::Result {
+        write!(writer, "{:x}", self)
+    }
+}
+
+impl BitPositions for U256 {
+    fn take_lowest_bit(&mut self) -> Option<u32> {
+        if *self == U256::ZERO {
+            return None;
+        }
+
+        let bit = self.trailing_zeros();
+        *self &= !(U256::ONE << bit);
+
+        Some(bit)
+    }
+
+    fn count_ones(&self) -> u32 {
+        U256::count_ones(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{parser, Flag, Flags};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Wide(U256);
+
+    impl Wide {
+        const LOW: Self = Wide(U256::ONE);
+        const HIGH: Self = Wide(U256::from_words(1 << 127, 0));
+    }
+
+    impl Flags for Wide {
+        const FLAGS: &'static [Flag<Self>] =
+            &[Flag::new("LOW", Wide::LOW), Flag::new("HIGH", Wide::HIGH)];
+
+        type Bits = U256;
+
+        fn bits(&self) -> U256 {
+            self.0
+        }
+
+        fn from_bits_retain(bits: U256) -> Self {
+            Wide(bits)
+        }
+    }
+
+    #[test]
+    fn from_bits() {
+        let high = U256::ONE << 255u32;
+
+        assert_eq!(Some(Wide::HIGH), Wide::from_bits(high));
+        assert_eq!(None, Wide::from_bits(high | U256::from(2u8)));
+        assert_eq!(Wide::LOW, Wide::from_bits_truncate(U256::MAX ^ high));
+    }
+
+    #[test]
+    fn iter() {
+        let flags = Wide::LOW
+            .union(Wide::HIGH)
+            .union(Wide::from_bits_retain(U256::from(4u8)));
+
+        assert_eq!(
+            vec![
+                Wide::LOW,
+                Wide::HIGH,
+                Wide::from_bits_retain(U256::from(4u8))
+            ],
+            flags.iter().collect::<Vec<_>>(),
+        );
+
+        assert_eq!(
+            vec![("LOW", Wide::LOW), ("HIGH", Wide::HIGH)],
+            flags.iter_names().collect::<Vec<_>>(),
+        );
+
+        assert_eq!(vec![0, 2, 255], flags.iter_bits().collect::<Vec<_>>());
+        assert_eq!(3, flags.bits_count());
+    }
+
+    #[test]
+    fn parse() {
+        let flags = Wide::LOW.union(Wide::from_bits_retain(U256::ONE << 200u32));
+
+        let mut formatted = String::new();
+        parser::to_writer(&flags, &mut formatted).unwrap();
+
+        assert_eq!(
+            "LOW | 0x100000000000000000000000000000000000000000000000000",
+            formatted
+        );
+        assert_eq!(flags, parser::from_str::<Wide>(&formatted).unwrap());
+
+        assert_eq!(
+            Wide::HIGH,
+            parser::from_str::<Wide>(
+                "0x8000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Wide::from_bits_retain(U256::MAX),
+            parser::from_str::<Wide>(&format!("0x{:x}", U256::MAX)).unwrap()
+        );
+
+        assert!(parser::from_str::<Wide>(
+            "0x1_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Specialized property testing for flags types using `proptest`.
+
+use core::fmt;
+
+use proptest::{arbitrary::Arbitrary, strategy::Strategy};
+
+use crate::Flags;
+
+/**
+Generate arbitrary flags values with any bits set, including unknown ones.
+*/
+pub fn strategy_any_bits<B: Flags + fmt::Debug>() -> impl Strategy<Value = B>
+where
+    B::Bits: Arbitrary,
+{
+    proptest::arbitrary::any::<B::Bits>().prop_map(B::from_bits_retain)
+}
+
+/**
+Generate arbitrary flags values with only known bits set.
+*/
+pub fn strategy_valid<B: Flags + fmt::Debug>() -> impl Strategy<Value = B>
+where
+    B::Bits: Arbitrary,
+{
+    proptest::arbitrary::any::<B::Bits>().prop_map(B::from_bits_truncate)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use proptest_derive::Arbitrary;
+
+    use super::*;
+
+    bitflags! {
+        #[derive(Arbitrary, Debug, PartialEq, Eq)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_proptest(color in any::<Color>()) {
+            prop_assert!(!color.contains_unknown_bits());
+        }
+
+        #[test]
+        fn test_strategy_valid(color in strategy_valid::<Color>()) {
+            prop_assert!(!color.contains_unknown_bits());
+        }
+
+        #[test]
+        fn test_strategy_any_bits_roundtrip(color in strategy_any_bits::<Color>()) {
+            let mut formatted = String::new();
+            crate::parser::to_writer(&color, &mut formatted).unwrap();
+
+            prop_assert_eq!(color, crate::parser::from_str::<Color>(&formatted).unwrap());
+        }
+    }
+}
@@ -0,0 +1,146 @@
+/*!
+Property testing for flags types using `quickcheck`.
+
+`quickcheck` doesn't have a derive for `Arbitrary`, so it needs to be implemented manually for
+a flags type using the functions in this module:
+
+```
+use bitflags::bitflags;
+use quickcheck::{Arbitrary, Gen};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Color: u8 {
+        const RED = 1;
+        const GREEN = 1 << 1;
+        const BLUE = 1 << 2;
+    }
+}
+
+impl Arbitrary for Color {
+    fn arbitrary(g: &mut Gen) -> Self {
+        bitflags::quickcheck::arbitrary(g)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        bitflags::quickcheck::shrink(self)
+    }
+}
+```
+*/
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{__private::alloc::boxed::Box, Flags};
+
+/**
+Generate an arbitrary flags value with only known bits set.
+
+Any unknown bits in the generated bits value are truncated.
+*/
+pub fn arbitrary<B: Flags>(g: &mut Gen) -> B
+where
+    B::Bits: Arbitrary,
+{
+    B::from_bits_truncate(B::Bits::arbitrary(g))
+}
+
+/**
+Generate an arbitrary flags value with any bits set, including unknown ones.
+*/
+pub fn arbitrary_retain<B: Flags>(g: &mut Gen) -> B
+where
+    B::Bits: Arbitrary,
+{
+    B::from_bits_retain(B::Bits::arbitrary(g))
+}
+
+/**
+Shrink a flags value towards [`Flags::empty`].
+
+Each yielded flags value has one of the flags yielded by [`Flags::iter`] removed,
+so any unknown bits are removed together.
+*/
+pub fn shrink<B: Flags + 'static>(flags: &B) -> Box<dyn Iterator<Item = B>> {
+    let source = flags.bits();
+
+    Box::new(
+        flags
+            .iter()
+            .map(move |flag| B::from_bits_retain(source).difference(flag)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    use super::*;
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Color: u8 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+            const PURPLE = 0x1 | 0x8;
+        }
+    }
+
+    impl Arbitrary for Color {
+        fn arbitrary(g: &mut Gen) -> Self {
+            arbitrary(g)
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            shrink(self)
+        }
+    }
+
+    #[test]
+    fn test_arbitrary() {
+        fn prop(color: Color) -> bool {
+            !color.contains_unknown_bits()
+        }
+
+        QuickCheck::new().quickcheck(prop as fn(Color) -> bool);
+    }
+
+    #[test]
+    fn test_arbitrary_retain() {
+        let mut g = Gen::new(256);
+
+        let unknown = (0..256)
+            .map(|_| arbitrary_retain::<Color>(&mut g))
+            .filter(|color| color.contains_unknown_bits())
+            .count();
+
+        assert_ne!(0, unknown);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let color = Color::RED | Color::BLUE | Color::from_bits_retain(0x10);
+
+        assert_eq!(
+            vec![
+                Color::BLUE | Color::from_bits_retain(0x10),
+                Color::RED | Color::from_bits_retain(0x10),
+                Color::RED | Color::BLUE,
+            ],
+            shrink(&color).collect::<Vec<_>>()
+        );
+
+        assert_eq!(0, shrink(&Color::empty()).count());
+    }
+
+    #[test]
+    fn test_shrink_internal() {
+        let color = Color::RED | Color::GREEN;
+
+        assert_eq!(
+            vec![Color::GREEN.0, Color::RED.0],
+            color.0.shrink().collect::<Vec<_>>()
+        );
+    }
+}
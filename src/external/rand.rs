@@ -0,0 +1,81 @@
+//! Specialized random sampling for flags types using `rand`.
+
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+use crate::Flags;
+
+/**
+Sample a random flags value with any bits set, including unknown ones.
+*/
+pub fn sample_any<B: Flags, R: Rng + ?Sized>(rng: &mut R) -> B
+where
+    StandardUniform: Distribution<B::Bits>,
+{
+    B::from_bits_retain(rng.random())
+}
+
+/**
+Sample a random flags value that's a union of defined flags.
+
+Each defined flag is included with equal probability. This is different from truncating a
+random bits value, which may produce bits that only partially overlap a multi-bit flag.
+*/
+pub fn sample_valid<B: Flags, R: Rng + ?Sized>(rng: &mut R) -> B {
+    let mut sampled = B::empty();
+
+    for flag in B::FLAGS {
+        if rng.random() {
+            sampled.insert(B::from_bits_retain(flag.value().bits()));
+        }
+    }
+
+    sampled
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Color: u8 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+            const PURPLE = 0x1 | 0x8;
+        }
+    }
+
+    #[test]
+    fn test_sample_valid() {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+
+        for _ in 0..256 {
+            let color = sample_valid::<Color, _>(&mut rng);
+
+            assert!(!color.contains_unknown_bits(), "{:?}", color);
+
+            // Bit 0x8 is only ever set as part of `PURPLE`
+            if color.bits() & 0x8 != 0 {
+                assert!(color.contains(Color::PURPLE), "{:?}", color);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_any() {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+
+        let unknown = (0..256)
+            .map(|_| sample_any::<Color, _>(&mut rng))
+            .filter(|color| color.contains_unknown_bits())
+            .count();
+
+        assert_ne!(0, unknown);
+    }
+}
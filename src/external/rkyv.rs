@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use rkyv::{rancor::Error, Archive, Deserialize, Serialize};
+
+    bitflags! {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq, Eq)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    #[test]
+    fn test_rkyv() {
+        for color in [
+            Color::empty(),
+            Color::RED | Color::BLUE,
+            Color::from_bits_retain(0x1 | 0x80),
+        ] {
+            let bytes = rkyv::to_bytes::<Error>(&color).unwrap();
+
+            assert_eq!(color.bits().to_le_bytes(), bytes.as_slice(), "{:?}", color);
+            assert_eq!(color, rkyv::from_bytes::<Color, Error>(&bytes).unwrap());
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Specialized JSON schemas for flags types using `schemars`.
+
+use schemars::{json_schema, Schema};
+
+use crate::{
+    __private::alloc::{format, string::String, vec::Vec},
+    Flags,
+};
+
+/**
+Generate a JSON schema for the human-readable form of a flags value.
+
+The schema describes a string of `|`-separated flags, where each flag is either the name of
+a defined flag or a number, as produced by the `serde` support. The names of defined
+flags are also listed as an `enum` so consumers can discover them.
+*/
+pub fn json_schema<B: Flags>() -> Schema {
+    let names = B::names().collect::<Vec<_>>();
+
+    let mut flag = String::from("0x[0-9a-fA-F]+|0b[0-1]+|0o[0-7]+");
+    for name in &names {
+        flag.push('|');
+        flag.push_str(name);
+    }
+
+    let pattern = format!(r"^\s*(({flag})\s*(\|\s*({flag})\s*)*)?$", flag = flag);
+
+    json_schema!({
+        "type": "string",
+        "description": "A set of flags separated by `|`",
+        "anyOf": [
+            { "enum": names },
+            { "pattern": pattern },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::JsonSchema;
+
+    bitflags! {
+        #[derive(JsonSchema)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+
+            const _ = 0x8;
+        }
+    }
+
+    #[test]
+    fn test_schemars() {
+        let schema = serde_json::to_value(schemars::schema_for!(Color)).unwrap();
+
+        assert_eq!("Color", schema["title"]);
+        assert_eq!("string", schema["type"]);
+        assert_eq!(
+            serde_json::json!(["RED", "GREEN", "BLUE"]),
+            schema["anyOf"][0]["enum"]
+        );
+    }
+
+    #[test]
+    fn test_schemars_pattern() {
+        let schema = serde_json::to_value(schemars::schema_for!(Color)).unwrap();
+        let pattern = schema["anyOf"][1]["pattern"].as_str().unwrap();
+
+        assert_eq!(
+            r"^\s*((0x[0-9a-fA-F]+|0b[0-1]+|0o[0-7]+|RED|GREEN|BLUE)\s*(\|\s*(0x[0-9a-fA-F]+|0b[0-1]+|0o[0-7]+|RED|GREEN|BLUE)\s*)*)?$",
+            pattern
+        );
+    }
+}
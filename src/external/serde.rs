@@ -1,12 +1,13 @@
 //! Specialized serialization for flags types using `serde`.
 
 use crate::{
-    parser::{self, ParseHex, WriteHex},
-    Flags,
+    parser::{self, ParseError, ParseHex, WriteHex},
+    Bits, Flags,
 };
 use core::{fmt, str};
 use serde::{
-    de::{Error, Visitor},
+    de::{DeserializeSeed, Error, SeqAccess, Visitor},
+    ser::SerializeSeq,
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -39,7 +40,133 @@ where
     B::Bits: ParseHex + Deserialize<'de>,
 {
     if deserializer.is_human_readable() {
-        // Deserialize human-readable flags by parsing them from strings like `"A | B"`
+        deserialize_text(deserializer)
+    } else {
+        // Deserialize non-human-readable flags directly from the underlying bits
+        let bits = B::Bits::deserialize(deserializer)?;
+
+        Ok(B::from_bits_retain(bits))
+    }
+}
+
+// Deserialize human-readable flags by parsing them from strings like `"A | B"`
+fn deserialize_text<'de, B: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex,
+{
+    struct FlagsVisitor<B>(core::marker::PhantomData<B>);
+
+    impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
+    where
+        B::Bits: ParseHex,
+    {
+        type Value = B;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a string value of `|` separated flags")
+        }
+
+        fn visit_str<E: Error>(self, flags: &str) -> Result<Self::Value, E> {
+            parser::from_str(flags).map_err(|e| E::custom(e))
+        }
+    }
+
+    deserializer.deserialize_str(FlagsVisitor(Default::default()))
+}
+
+/**
+Deserialize a set of flags from a human-readable string or their underlying bits,
+rejecting any unknown bits.
+
+This function is like [`deserialize`], except it fails if the deserialized flags value has any
+bits set that don't correspond to a defined flag, including hex numbers in human-readable formats.
+The error includes the unknown bits.
+
+This function can be used with `#[serde(deserialize_with = "bitflags::serde::deserialize_bits_strict")]`.
+*/
+pub fn deserialize_bits_strict<'de, B: Flags, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + WriteHex + Deserialize<'de>,
+{
+    let flags: B = deserialize(deserializer)?;
+
+    let bits = flags.bits();
+    let unknown = bits & !B::all().bits();
+    if unknown != B::Bits::EMPTY {
+        return Err(D::Error::custom(format_args!(
+            "unknown bits `{}` in `{}`",
+            AsHex(unknown),
+            AsHex(bits)
+        )));
+    }
+
+    Ok(flags)
+}
+
+/**
+Serialize a set of flags as a human-readable sequence of names or their underlying bits.
+
+Each contained named flag is serialized as its name, like `["A", "B"]`. Any remaining bits are
+serialized as a final hex string, like `["A", "B", "0x8"]`.
+
+This function can be used with `#[serde(serialize_with = "bitflags::serde::serialize_as_names")]`
+instead of the default `|`-separated string format.
+*/
+pub fn serialize_as_names<B: Flags, S: Serializer>(
+    flags: &B,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex + Serialize,
+{
+    // Serialize human-readable flags as a sequence like `["A", "B"]`
+    if serializer.is_human_readable() {
+        let mut iter = flags.iter_names();
+        let mut len = (&mut iter).count();
+
+        let remaining = iter.remaining().bits();
+        if remaining != B::Bits::EMPTY {
+            len += 1;
+        }
+
+        let mut seq = serializer.serialize_seq(Some(len))?;
+
+        let mut iter = flags.iter_names();
+        for (name, _) in &mut iter {
+            seq.serialize_element(name)?;
+        }
+
+        if remaining != B::Bits::EMPTY {
+            seq.serialize_element(&AsHex(remaining))?;
+        }
+
+        seq.end()
+    }
+    // Serialize non-human-readable flags directly as the underlying bits
+    else {
+        flags.bits().serialize(serializer)
+    }
+}
+
+/**
+Deserialize a set of flags from a human-readable sequence of names or their underlying bits.
+
+Each element must be the name of a defined flag, or a hex string like `"0x8"`.
+Any unknown bits will be retained.
+
+This function can be used with `#[serde(deserialize_with = "bitflags::serde::deserialize_from_names")]`
+to read values written by [`serialize_as_names`].
+*/
+pub fn deserialize_from_names<'de, B: Flags, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + Deserialize<'de>,
+{
+    if deserializer.is_human_readable() {
+        // Deserialize human-readable flags by parsing them from sequences like `["A", "B"]`
         struct FlagsVisitor<B>(core::marker::PhantomData<B>);
 
         impl<'de, B: Flags> Visitor<'de> for FlagsVisitor<B>
@@ -49,15 +176,59 @@ where
             type Value = B;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("a string value of `|` separated flags")
+                formatter.write_str("a sequence of flag names")
             }
 
-            fn visit_str<E: Error>(self, flags: &str) -> Result<Self::Value, E> {
-                parser::from_str(flags).map_err(|e| E::custom(e))
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut parsed = B::Bits::EMPTY;
+
+                while let Some(flag) =
+                    seq.next_element_seed(FlagVisitor::<B>(Default::default()))?
+                {
+                    parsed = parsed | flag.bits();
+                }
+
+                Ok(B::from_bits_retain(parsed))
             }
         }
 
-        deserializer.deserialize_str(FlagsVisitor(Default::default()))
+        // Deserialize a single element, which is either a name or a hex number
+        struct FlagVisitor<B>(core::marker::PhantomData<B>);
+
+        impl<'de, B: Flags> DeserializeSeed<'de> for FlagVisitor<B>
+        where
+            B::Bits: ParseHex,
+        {
+            type Value = B;
+
+            fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<B, D::Error> {
+                deserializer.deserialize_str(self)
+            }
+        }
+
+        impl<'de, B: Flags> Visitor<'de> for FlagVisitor<B>
+        where
+            B::Bits: ParseHex,
+        {
+            type Value = B;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a flag name or hex number")
+            }
+
+            fn visit_str<E: Error>(self, flag: &str) -> Result<Self::Value, E> {
+                if let Some(flag) = flag.strip_prefix("0x") {
+                    let bits = B::Bits::parse_hex(flag).map_err(|e| E::custom(e))?;
+
+                    Ok(B::from_bits_retain(bits))
+                } else {
+                    B::from_name(flag)
+                        .ok_or_else(|| E::custom(ParseError::invalid_named_flag(flag)))
+                }
+            }
+        }
+
+        deserializer.deserialize_seq(FlagsVisitor(Default::default()))
     } else {
         // Deserialize non-human-readable flags directly from the underlying bits
         let bits = B::Bits::deserialize(deserializer)?;
@@ -66,9 +237,144 @@ where
     }
 }
 
+/**
+Serialize a set of flags as a human-readable string or a fixed-width array of bytes.
+
+Non-human-readable flags are serialized with `serialize_bytes` as exactly `size_of::<B::Bits>()`
+little-endian bytes, instead of as their underlying bits. This is useful for formats like `postcard`
+that would otherwise use a variable-width encoding for integers.
+
+This function can be used with `#[serde(serialize_with = "bitflags::serde::serialize_bits_fixed")]`.
+*/
+pub fn serialize_bits_fixed<B: Flags, S: Serializer>(
+    flags: &B,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    B::Bits: WriteHex + FixedBytes,
+{
+    // Serialize human-readable flags as a string like `"A | B"`
+    if serializer.is_human_readable() {
+        serializer.collect_str(&parser::AsDisplay(flags))
+    }
+    // Serialize non-human-readable flags as the bytes of the underlying bits
+    else {
+        serializer.serialize_bytes(flags.bits().to_fixed_bytes().as_ref())
+    }
+}
+
+/**
+Deserialize a set of flags from a human-readable string or a fixed-width array of bytes.
+
+Any unknown bits will be retained.
+
+This function can be used with `#[serde(deserialize_with = "bitflags::serde::deserialize_bits_fixed")]`
+to read values written by [`serialize_bits_fixed`].
+*/
+pub fn deserialize_bits_fixed<'de, B: Flags, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<B, D::Error>
+where
+    B::Bits: ParseHex + FixedBytes,
+{
+    if deserializer.is_human_readable() {
+        deserialize_text(deserializer)
+    } else {
+        // Deserialize non-human-readable flags from the bytes of the underlying bits
+        struct BytesVisitor<B>(core::marker::PhantomData<B>);
+
+        impl<'de, B: Flags> Visitor<'de> for BytesVisitor<B>
+        where
+            B::Bits: FixedBytes,
+        {
+            type Value = B;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{} bytes",
+                    B::Bits::EMPTY.to_fixed_bytes().as_ref().len()
+                )
+            }
+
+            fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+                let bits = B::Bits::from_fixed_bytes(bytes)
+                    .ok_or_else(|| E::invalid_length(bytes.len(), &self))?;
+
+                Ok(B::from_bits_retain(bits))
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor(Default::default()))
+    }
+}
+
+/**
+A bits type that can be converted to and from a fixed number of bytes.
+
+This trait is implemented for all primitive integers, using little-endian byte order.
+*/
+pub trait FixedBytes: Bits {
+    /// The bytes of a bits value.
+    type Bytes: AsRef<[u8]>;
+
+    /// Convert a bits value into its bytes.
+    fn to_fixed_bytes(&self) -> Self::Bytes;
+
+    /// Convert bytes into a bits value.
+    ///
+    /// This method will return `None` if the number of bytes doesn't match the size of `Self`.
+    fn from_fixed_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_fixed_bytes {
+    ($($t:ty,)*) => {
+        $(
+            impl FixedBytes for $t {
+                type Bytes = [u8; core::mem::size_of::<$t>()];
+
+                fn to_fixed_bytes(&self) -> Self::Bytes {
+                    self.to_le_bytes()
+                }
+
+                fn from_fixed_bytes(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(<$t>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_bytes! {
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize,
+}
+
+// Serialize bits as a hex string like `"0x8"`
+struct AsHex<B>(B);
+
+impl<B: WriteHex> fmt::Display for AsHex<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("0x")?;
+        self.0.write_hex(f)
+    }
+}
+
+impl<B: WriteHex> Serialize for AsHex<B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use serde_test::{assert_tokens, Configure, Token::*};
+    use serde_test::{
+        assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token::*,
+    };
     bitflags! {
         #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
         #[serde(transparent)]
@@ -90,4 +396,239 @@ mod tests {
 
         assert_tokens(&(SerdeFlags::A | SerdeFlags::B).compact(), &[U32(1 | 2)]);
     }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    struct SerdeNames {
+        #[serde(
+            serialize_with = "crate::serde::serialize_as_names",
+            deserialize_with = "crate::serde::deserialize_from_names"
+        )]
+        flags: SerdeFlags,
+    }
+
+    fn names(flags: SerdeFlags) -> SerdeNames {
+        SerdeNames { flags }
+    }
+
+    #[test]
+    fn test_serde_bitflags_names() {
+        assert_tokens(
+            &names(SerdeFlags::empty()).readable(),
+            &[
+                Struct {
+                    name: "SerdeNames",
+                    len: 1,
+                },
+                Str("flags"),
+                Seq {
+                    len: Option::Some(0),
+                },
+                SeqEnd,
+                StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &names(SerdeFlags::A | SerdeFlags::B).readable(),
+            &[
+                Struct {
+                    name: "SerdeNames",
+                    len: 1,
+                },
+                Str("flags"),
+                Seq {
+                    len: Option::Some(2),
+                },
+                Str("A"),
+                Str("B"),
+                SeqEnd,
+                StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &names(SerdeFlags::A | SerdeFlags::from_bits_retain(0x30)).readable(),
+            &[
+                Struct {
+                    name: "SerdeNames",
+                    len: 1,
+                },
+                Str("flags"),
+                Seq {
+                    len: Option::Some(2),
+                },
+                Str("A"),
+                Str("0x30"),
+                SeqEnd,
+                StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &names(SerdeFlags::A | SerdeFlags::B).compact(),
+            &[
+                Struct {
+                    name: "SerdeNames",
+                    len: 1,
+                },
+                Str("flags"),
+                U32(1 | 2),
+                StructEnd,
+            ],
+        );
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    struct SerdeFixed {
+        #[serde(
+            serialize_with = "crate::serde::serialize_bits_fixed",
+            deserialize_with = "crate::serde::deserialize_bits_fixed"
+        )]
+        flags: SerdeFlags,
+    }
+
+    fn fixed(flags: SerdeFlags) -> SerdeFixed {
+        SerdeFixed { flags }
+    }
+
+    #[test]
+    fn test_serde_bitflags_fixed() {
+        assert_tokens(
+            &fixed(SerdeFlags::A | SerdeFlags::B).readable(),
+            &[
+                Struct {
+                    name: "SerdeFixed",
+                    len: 1,
+                },
+                Str("flags"),
+                Str("A | B"),
+                StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &fixed(SerdeFlags::A | SerdeFlags::B).compact(),
+            &[
+                Struct {
+                    name: "SerdeFixed",
+                    len: 1,
+                },
+                Str("flags"),
+                Bytes(&[1 | 2, 0, 0, 0]),
+                StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &fixed(SerdeFlags::A | SerdeFlags::from_bits_retain(0x1_00)).compact(),
+            &[
+                Struct {
+                    name: "SerdeFixed",
+                    len: 1,
+                },
+                Str("flags"),
+                Bytes(&[1, 1, 0, 0]),
+                StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_serde_bitflags_fixed_invalid() {
+        assert_de_tokens_error::<Compact<SerdeFixed>>(
+            &[
+                Struct {
+                    name: "SerdeFixed",
+                    len: 1,
+                },
+                Str("flags"),
+                Bytes(&[1, 0]),
+            ],
+            "invalid length 2, expected 4 bytes",
+        );
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq, Eq)]
+    struct SerdeStrict {
+        #[serde(deserialize_with = "crate::serde::deserialize_bits_strict")]
+        flags: SerdeFlags,
+    }
+
+    fn strict(flags: SerdeFlags) -> SerdeStrict {
+        SerdeStrict { flags }
+    }
+
+    #[test]
+    fn test_serde_bitflags_strict() {
+        assert_tokens(
+            &strict(SerdeFlags::A | SerdeFlags::D).compact(),
+            &[
+                Struct {
+                    name: "SerdeStrict",
+                    len: 1,
+                },
+                Str("flags"),
+                U32(1 | 8),
+                StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &strict(SerdeFlags::A | SerdeFlags::D).readable(),
+            &[
+                Struct {
+                    name: "SerdeStrict",
+                    len: 1,
+                },
+                Str("flags"),
+                Str("A | D"),
+                StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_serde_bitflags_strict_invalid() {
+        assert_de_tokens_error::<Compact<SerdeStrict>>(
+            &[
+                Struct {
+                    name: "SerdeStrict",
+                    len: 1,
+                },
+                Str("flags"),
+                U32(1 | 0x30),
+            ],
+            "unknown bits `0x30` in `0x31`",
+        );
+
+        assert_de_tokens_error::<Readable<SerdeStrict>>(
+            &[
+                Struct {
+                    name: "SerdeStrict",
+                    len: 1,
+                },
+                Str("flags"),
+                Str("A | 0x30"),
+            ],
+            "unknown bits `0x30` in `0x31`",
+        );
+    }
+
+    #[test]
+    fn test_serde_bitflags_names_invalid() {
+        assert_de_tokens_error::<Readable<SerdeNames>>(
+            &[
+                Struct {
+                    name: "SerdeNames",
+                    len: 1,
+                },
+                Str("flags"),
+                Seq {
+                    len: Option::Some(1),
+                },
+                Str("E"),
+            ],
+            "unrecognized named flag `E`",
+        );
+    }
 }
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use speedy::{BigEndian, LittleEndian, Readable, Writable};
+
+    bitflags! {
+        #[derive(Readable, Writable, Debug, PartialEq, Eq)]
+        struct Color: u32 {
+            const RED = 0x1;
+            const GREEN = 0x2;
+            const BLUE = 0x4;
+        }
+    }
+
+    #[test]
+    fn test_speedy() {
+        for color in [
+            Color::empty(),
+            Color::RED | Color::BLUE,
+            Color::from_bits_retain(0x1 | 0x80),
+        ] {
+            let bytes = color
+                .write_to_vec_with_ctx(LittleEndian::default())
+                .unwrap();
+
+            assert_eq!(color.bits().to_le_bytes(), bytes.as_slice(), "{:?}", color);
+            assert_eq!(
+                color,
+                Color::read_from_buffer_with_ctx(LittleEndian::default(), &bytes).unwrap()
+            );
+
+            let bytes = color.write_to_vec_with_ctx(BigEndian::default()).unwrap();
+
+            assert_eq!(color.bits().to_be_bytes(), bytes.as_slice(), "{:?}", color);
+            assert_eq!(
+                color,
+                Color::read_from_buffer_with_ctx(BigEndian::default(), &bytes).unwrap()
+            );
+        }
+    }
+}
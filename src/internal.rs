@@ -29,6 +29,8 @@ macro_rules! __declare_internal_bitflags {
 #[doc(hidden)]
 macro_rules! __impl_internal_bitflags {
     (
+        inline: $inline:meta,
+        debug: $debug:ident,
         $InternalBitFlags:ident: $T:ty, $PublicBitFlags:ident {
             $(
                 $(#[$inner:ident $($args:tt)*])*
@@ -52,6 +54,7 @@ macro_rules! __impl_internal_bitflags {
         }
 
         impl $crate::__private::core::fmt::Debug for $InternalBitFlags {
+            #[$inline]
             fn fmt(&self, f: &mut $crate::__private::core::fmt::Formatter<'_>) -> $crate::__private::core::fmt::Result {
                 if self.is_empty() {
                     // If no flags are set then write an empty hex flag to avoid
@@ -64,7 +67,14 @@ macro_rules! __impl_internal_bitflags {
                     // just like `0x0` does.
                     $crate::__private::core::write!(f, "{:#x}", <$T as $crate::Bits>::EMPTY)
                 } else {
-                    $crate::__private::core::fmt::Display::fmt(self, f)
+                    if f.alternate() {
+                        // When pretty-printing, write each flag on its own line
+                        $crate::parser::to_writer_with(&$PublicBitFlags(*self), &mut *f, " |\n")?;
+                    } else {
+                        $crate::__private::core::fmt::Display::fmt(self, f)?;
+                    }
+
+                    $crate::__impl_internal_bitflags_debug_bits!($debug, f, self.bits())
                 }
             }
         }
@@ -98,6 +108,7 @@ macro_rules! __impl_internal_bitflags {
         // The internal flags type offers a similar API to the public one
 
         $crate::__impl_public_bitflags! {
+            inline: $inline,
             $InternalBitFlags: $T, $PublicBitFlags {
                 $(
                     $(#[$inner $($args)*])*
@@ -111,6 +122,7 @@ macro_rules! __impl_internal_bitflags {
         }
 
         $crate::__impl_public_bitflags_iter! {
+            inline: $inline,
             $InternalBitFlags: $T, $PublicBitFlags
         }
 
@@ -123,3 +135,17 @@ macro_rules! __impl_internal_bitflags {
         }
     };
 }
+
+/// Write the end of the `Debug` format for a non-empty internal bitflags type.
+///
+/// With `names_and_bits`, the raw bits are written after the names of flags.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __impl_internal_bitflags_debug_bits {
+    (names, $f:expr, $bits:expr) => {
+        $crate::__private::core::fmt::Result::Ok(())
+    };
+    (names_and_bits, $f:expr, $bits:expr) => {
+        $crate::__private::core::write!($f, " = {:#x}", $bits)
+    };
+}
@@ -2,13 +2,16 @@
 Yield the bits of a source flags value in a set of contained flags values.
 */
 
-use crate::{Flag, Flags};
+use crate::{Bits, Flag, Flags};
 
 /**
 An iterator over flags values.
 
 This iterator will yield flags values for contained, defined flags first, with any remaining bits yielded
 as a final flags value.
+
+When iterated in reverse, any remaining bits are yielded first, followed by flags values for
+contained, defined flags in the reverse order they'd be yielded when iterating forwards.
 */
 pub struct Iter<B: 'static> {
     inner: IterNames<B>,
@@ -25,16 +28,39 @@ impl<B: Flags> Iter<B> {
 }
 
 impl<B: 'static> Iter<B> {
+    /// Get a flags value of any remaining bits that haven't been covered by a named flag yet.
+    ///
+    /// When iterating forwards, these bits are yielded together as a final flags value.
+    /// Once the iterator has finished, this method can be used to check which bits didn't
+    /// correspond to a contained, defined, named flag.
+    pub fn remaining(&self) -> &B {
+        self.inner.remaining()
+    }
+
     // Used by the `bitflags` macro
     #[doc(hidden)]
-    pub const fn __private_const_new(flags: &'static [Flag<B>], source: B, remaining: B) -> Self {
+    pub const fn __private_const_new(
+        flags: &'static [Flag<B>],
+        source: B,
+        remaining: B,
+        front: B,
+    ) -> Self {
         Iter {
-            inner: IterNames::__private_const_new(flags, source, remaining),
+            inner: IterNames::__private_const_new(flags, source, remaining, front),
             done: false,
         }
     }
 }
 
+impl<B: Flags> Clone for Iter<B> {
+    fn clone(&self) -> Self {
+        Iter {
+            inner: self.inner.clone(),
+            done: self.done,
+        }
+    }
+}
+
 impl<B: Flags> Iterator for Iter<B> {
     type Item = B;
 
@@ -58,17 +84,42 @@ impl<B: Flags> Iterator for Iter<B> {
     }
 }
 
+impl<B: Flags> DoubleEndedIterator for Iter<B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.done {
+            self.done = true;
+
+            // Any bits that won't be covered by a flag are yielded first
+            let unknown = self.inner.front_at(self.inner.flags.len());
+            if !unknown.is_empty() {
+                self.inner
+                    .remaining
+                    .remove(B::from_bits_retain(unknown.bits()));
+
+                return Some(unknown);
+            }
+        }
+
+        self.inner.next_back().map(|(_, flag)| flag)
+    }
+}
+
 /**
 An iterator over flags values.
 
 This iterator only yields flags values for contained, defined, named flags. Any remaining bits
 won't be yielded, but can be found with the [`IterNames::remaining`] method.
+
+When iterated in reverse, flags values are yielded in the reverse order they'd be yielded
+when iterating forwards.
 */
 pub struct IterNames<B: 'static> {
     flags: &'static [Flag<B>],
     idx: usize,
+    back: usize,
     source: B,
     remaining: B,
+    front: B,
 }
 
 impl<B: Flags> IterNames<B> {
@@ -76,8 +127,10 @@ impl<B: Flags> IterNames<B> {
         IterNames {
             flags: B::FLAGS,
             idx: 0,
+            back: B::FLAGS.len(),
             remaining: B::from_bits_retain(flags.bits()),
             source: B::from_bits_retain(flags.bits()),
+            front: B::from_bits_retain(flags.bits()),
         }
     }
 }
@@ -85,12 +138,19 @@ impl<B: Flags> IterNames<B> {
 impl<B: 'static> IterNames<B> {
     // Used by the bitflags macro
     #[doc(hidden)]
-    pub const fn __private_const_new(flags: &'static [Flag<B>], source: B, remaining: B) -> Self {
+    pub const fn __private_const_new(
+        flags: &'static [Flag<B>],
+        source: B,
+        remaining: B,
+        front: B,
+    ) -> Self {
         IterNames {
             flags,
             idx: 0,
+            back: flags.len(),
             remaining,
             source,
+            front,
         }
     }
 
@@ -104,36 +164,106 @@ impl<B: 'static> IterNames<B> {
     }
 }
 
+impl<B: Flags> IterNames<B> {
+    // Whether the flag would be yielded when iterating forwards, given the bits
+    // that haven't been covered by a previous flag yet
+    fn step(source: &B, front: &mut B, flag: &Flag<B>) -> bool {
+        // Skip unnamed flags
+        if flag.name().is_empty() {
+            return false;
+        }
+
+        let bits = flag.value().bits();
+
+        // If the flag is set in the original source _and_ it has bits that haven't
+        // been covered by a previous flag yet then yield it. These conditions cover
+        // two cases for multi-bit flags:
+        //
+        // 1. When flags partially overlap, such as `0b00000001` and `0b00000101`, we'll
+        // yield both flags.
+        // 2. When flags fully overlap, such as in convenience flags that are a shorthand for others,
+        // we won't yield both flags.
+        if source.contains(B::from_bits_retain(bits)) && front.intersects(B::from_bits_retain(bits))
+        {
+            front.remove(B::from_bits_retain(bits));
+
+            true
+        } else {
+            false
+        }
+    }
+
+    // Get the bits that haven't been covered by a flag yet when iterating forwards up to `end`
+    fn front_at(&self, end: usize) -> B {
+        let mut front = B::from_bits_retain(self.front.bits());
+
+        for flag in &self.flags[self.idx..end] {
+            // Short-circuit if our state is empty
+            if front.is_empty() {
+                break;
+            }
+
+            Self::step(&self.source, &mut front, flag);
+        }
+
+        front
+    }
+}
+
+impl<B: Flags> Clone for IterNames<B> {
+    fn clone(&self) -> Self {
+        IterNames {
+            flags: self.flags,
+            idx: self.idx,
+            back: self.back,
+            source: B::from_bits_retain(self.source.bits()),
+            remaining: B::from_bits_retain(self.remaining.bits()),
+            front: B::from_bits_retain(self.front.bits()),
+        }
+    }
+}
+
 impl<B: Flags> Iterator for IterNames<B> {
     type Item = (&'static str, B);
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(flag) = self.flags.get(self.idx) {
+        while self.idx < self.back {
             // Short-circuit if our state is empty
-            if self.remaining.is_empty() {
+            if self.front.is_empty() {
                 return None;
             }
 
+            let flag = &self.flags[self.idx];
             self.idx += 1;
 
-            // Skip unnamed flags
-            if flag.name().is_empty() {
-                continue;
+            if Self::step(&self.source, &mut self.front, flag) {
+                let bits = flag.value().bits();
+                self.remaining.remove(B::from_bits_retain(bits));
+
+                return Some((flag.name(), B::from_bits_retain(bits)));
+            }
+        }
+
+        None
+    }
+}
+
+impl<B: Flags> DoubleEndedIterator for IterNames<B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Whether a flag is yielded depends on the flags before it, so to match
+        // forwards iteration we need to replay those flags for each candidate
+        while self.idx < self.back {
+            // Short-circuit if our state is empty
+            if self.front.is_empty() {
+                return None;
             }
 
-            let bits = flag.value().bits();
-
-            // If the flag is set in the original source _and_ it has bits that haven't
-            // been covered by a previous flag yet then yield it. These conditions cover
-            // two cases for multi-bit flags:
-            //
-            // 1. When flags partially overlap, such as `0b00000001` and `0b00000101`, we'll
-            // yield both flags.
-            // 2. When flags fully overlap, such as in convenience flags that are a shorthand for others,
-            // we won't yield both flags.
-            if self.source.contains(B::from_bits_retain(bits))
-                && self.remaining.intersects(B::from_bits_retain(bits))
-            {
+            self.back -= 1;
+            let flag = &self.flags[self.back];
+
+            let mut front = self.front_at(self.back);
+            if Self::step(&self.source, &mut front, flag) {
+                let bits = flag.value().bits();
                 self.remaining.remove(B::from_bits_retain(bits));
 
                 return Some((flag.name(), B::from_bits_retain(bits)));
@@ -143,3 +273,204 @@ impl<B: Flags> Iterator for IterNames<B> {
         None
     }
 }
+
+/**
+An iterator over every named flag contained in a flags value.
+
+Unlike [`IterNames`], this iterator doesn't skip flags whose bits were already covered by
+a previous flag, so every overlapping flag that's contained is yielded. Flags with no bits set
+are never yielded.
+*/
+pub struct MatchingFlags<B: 'static> {
+    flags: &'static [Flag<B>],
+    source: B,
+}
+
+impl<B: Flags> MatchingFlags<B> {
+    pub(crate) fn new(flags: &B) -> Self {
+        MatchingFlags {
+            flags: B::FLAGS,
+            source: B::from_bits_retain(flags.bits()),
+        }
+    }
+
+    fn matches(&self, flag: &Flag<B>) -> bool {
+        let bits = flag.value().bits();
+
+        flag.is_named() && bits != B::Bits::EMPTY && self.source.contains(B::from_bits_retain(bits))
+    }
+}
+
+impl<B: Flags> Clone for MatchingFlags<B> {
+    fn clone(&self) -> Self {
+        MatchingFlags {
+            flags: self.flags,
+            source: B::from_bits_retain(self.source.bits()),
+        }
+    }
+}
+
+impl<B: Flags> Iterator for MatchingFlags<B> {
+    type Item = (&'static str, B);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((flag, rest)) = self.flags.split_first() {
+            self.flags = rest;
+
+            if self.matches(flag) {
+                return Some((flag.name(), B::from_bits_retain(flag.value().bits())));
+            }
+        }
+
+        None
+    }
+}
+
+impl<B: Flags> DoubleEndedIterator for MatchingFlags<B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some((flag, rest)) = self.flags.split_last() {
+            self.flags = rest;
+
+            if self.matches(flag) {
+                return Some((flag.name(), B::from_bits_retain(flag.value().bits())));
+            }
+        }
+
+        None
+    }
+}
+
+/**
+An iterator over the positions of set bits in a flags value.
+
+This iterator yields the index of each set bit in ascending order, regardless of whether
+it's part of a defined flag.
+*/
+pub struct IterBits<B: 'static> {
+    remaining: B,
+}
+
+impl<B: Flags> IterBits<B> {
+    pub(crate) fn new(flags: &B) -> Self {
+        IterBits {
+            remaining: B::from_bits_retain(flags.bits()),
+        }
+    }
+}
+
+impl<B: 'static> IterBits<B> {
+    // Used by the bitflags macro
+    #[doc(hidden)]
+    pub const fn __private_const_new(remaining: B) -> Self {
+        IterBits { remaining }
+    }
+}
+
+impl<B: Flags> Clone for IterBits<B> {
+    fn clone(&self) -> Self {
+        IterBits {
+            remaining: B::from_bits_retain(self.remaining.bits()),
+        }
+    }
+}
+
+impl<B: Flags> Iterator for IterBits<B>
+where
+    B::Bits: BitPositions,
+{
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut bits = self.remaining.bits();
+        let bit = bits.take_lowest_bit()?;
+
+        self.remaining = B::from_bits_retain(bits);
+
+        Some(bit)
+    }
+}
+
+/**
+An iterator over the names of defined flags.
+
+This iterator yields the name of each named flag in the order they're defined,
+including flags with overlapping or zero bits.
+*/
+pub struct Names<B: 'static> {
+    flags: &'static [Flag<B>],
+}
+
+impl<B: Flags> Names<B> {
+    pub(crate) fn new() -> Self {
+        Names { flags: B::FLAGS }
+    }
+}
+
+impl<B: 'static> Names<B> {
+    // Used by the bitflags macro
+    #[doc(hidden)]
+    pub const fn __private_const_new(flags: &'static [Flag<B>]) -> Self {
+        Names { flags }
+    }
+}
+
+impl<B: 'static> Clone for Names<B> {
+    fn clone(&self) -> Self {
+        Names { flags: self.flags }
+    }
+}
+
+impl<B: 'static> Iterator for Names<B> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((flag, rest)) = self.flags.split_first() {
+            self.flags = rest;
+
+            if flag.is_named() {
+                return Some(flag.name());
+            }
+        }
+
+        None
+    }
+}
+
+impl<B: 'static> DoubleEndedIterator for Names<B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some((flag, rest)) = self.flags.split_last() {
+            self.flags = rest;
+
+            if flag.is_named() {
+                return Some(flag.name());
+            }
+        }
+
+        None
+    }
+}
+
+/**
+A bits type that can yield the positions of its set bits.
+*/
+pub trait BitPositions: Bits {
+    /// Unset the lowest set bit, returning its position.
+    ///
+    /// This method will return `None` if no bits are set.
+    fn take_lowest_bit(&mut self) -> Option<u32>;
+
+    /// The number of set bits.
+    ///
+    /// The default implementation unsets bits one at a time with [`BitPositions::take_lowest_bit`].
+    /// Bits types with a faster popcount, like primitive integers, can override it.
+    fn count_ones(&self) -> u32 {
+        let mut bits = *self;
+        let mut count = 0;
+
+        while bits.take_lowest_bit().is_some() {
+            count += 1;
+        }
+
+        count
+    }
+}
@@ -74,7 +74,23 @@ libraries are currently supported:
   and a raw number for binary formats.
 - `arbitrary`: Support `#[derive(Arbitrary)]`, only generating flags values with known bits.
 - `bytemuck`: Support `#[derive(Pod, Zeroable)]`, for casting between flags values and their
+  underlying bits values. `Pod` flags types accept any bits, including unknown ones. To reject
+  unknown bits in `bytemuck::checked` casts, implement `CheckedBitPattern` using `from_bits` instead.
+- `defmt`: Support `#[derive(defmt::Format)]`, using the same text format as `Display`.
+- `proptest`: Support `#[derive(proptest_derive::Arbitrary)]`, only generating flags values with known bits.
+- `quickcheck`: Generate and shrink flags values in `quickcheck` properties with the functions in
+  the `quickcheck` module.
+- `rkyv`: Support `#[derive(Archive, Serialize, Deserialize)]`, archiving flags values as their
   underlying bits values.
+- `borsh`: Support `#[derive(BorshSerialize, BorshDeserialize)]`, using the underlying bits value.
+- `speedy`: Support `#[derive(Readable, Writable)]`, using the underlying bits value in the
+  context's endianness.
+- `schemars`: Support `#[derive(JsonSchema)]`, describing the same text format used by `serde`.
+- `databake`: Implement `Bake` for flags types with a `#[bitflags(databake_path = "..")]` attribute,
+  baking them as a call to `from_bits_retain` through the given module path.
+- `rand`: Sample random flags values with the functions in the `rand` module.
+- `ethnum`: Use `ethnum::U256` as the bits type of a manually implemented [`Flags`] type,
+  including parsing, formatting, and iterating over bits.
 
 You can also define your own flags type outside of the [`bitflags`] macro and then use it to generate methods.
 This can be useful if you need a custom `#[derive]` attribute for a library that `bitflags` doesn't
@@ -153,6 +169,8 @@ See the docs for the [`Flags`] trait for more details on operators and how they
 # Formatting and parsing
 
 `bitflags` defines a text format that can be used to convert any flags value to and from strings.
+The `Debug` implementation of a generated flags type also uses this format. When pretty-printed
+with `{:#?}`, each flag is written on its own line.
 
 See the [`parser`] module for more details.
 
@@ -243,7 +261,7 @@ The result of `Flags::A ^ Flags::B` is `0b0000_0010`, which doesn't correspond t
 #![cfg_attr(test, allow(mixed_script_confusables))]
 
 #[doc(inline)]
-pub use traits::{Bits, Flag, Flags};
+pub use traits::{Bits, Flag, Flags, UnknownBits};
 
 pub mod iter;
 pub mod parser;
@@ -353,6 +371,23 @@ bitflags! {
 }
 ```
 
+Attributes on the declaration, like `#[derive]`, `#[repr]`, or `#[non_exhaustive]`, are forwarded
+to the generated struct. Its single field is always private, so values can't be constructed or
+destructured by pattern outside the defining module. Use methods like `from_bits_retain` instead:
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub struct Flags: u8 {
+        const A = 1;
+    }
+}
+
+assert_eq!(Flags::A, Flags::from_bits_retain(1));
+```
+
 Flags may refer to other flags using their [`Flags::bits`] value:
 
 ```
@@ -366,6 +401,20 @@ bitflags! {
 }
 ```
 
+Flags are terminated by `;`, like other constants. Flags may also be separated by `,`, like enum
+variants, and the two forms can be mixed:
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    struct Flags: u8 {
+        const A = 1,
+        const B = 1 << 1,
+        const C = 1 << 2;
+    }
+}
+```
+
 A single `bitflags` invocation may include zero or more flags type declarations:
 
 ```
@@ -390,6 +439,12 @@ A declaration that begins with `impl` will only generate methods and trait imple
 
 The struct itself must be a newtype using the bits type as its field.
 
+Like in `struct` mode, the bits type must be a primitive integer. The generated methods are
+`const fn`s, and operators on custom [`Bits`] types can't be called in `const` contexts on stable
+Rust. For a newtype over a custom bits type, implement [`Flags`] manually instead. Its provided
+methods cover the same operations, written in terms of [`Bits::EMPTY`], [`Bits::ALL`],
+and the bitwise operators.
+
 The syntax for `impl` mode is identical to `struct` mode besides the starting token.
 
 ## Examples
@@ -442,11 +497,289 @@ bitflags! {
     }
 }
 ```
+
+# Flag values
+
+The value of a flag is any constant expression of the bits type. It can refer to other constants,
+including the bits of other flags on the same type through `Self`, or flags on a different type.
+Use `.bits()` to get the bits of a flags value, since the value of a flag is a bits value rather
+than a flags value. Constants can refer to each other in any order, as long as they don't form a cycle.
+
+## Examples
+
+Combining flags from another flags type:
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    struct Flags1: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+bitflags! {
+    struct Flags2: u8 {
+        const A = Flags1::A.bits() | 0b1000;
+        const AB = Self::A.bits() | Flags1::B.bits();
+    }
+}
+
+assert_eq!(0b1011, Flags2::AB.bits());
+```
+
+# Non-exhaustive flags
+
+The `#[bitflags(non_exhaustive)]` attribute on a flags type makes all bits known. It's equivalent
+to adding an unnamed flag with all bits set. This is useful for flags types that represent bits
+owned by external sources, where any bits may be set. Named flags are still formatted by name,
+with any remaining bits formatted as hex.
+
+## Examples
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[derive(Debug)]
+    #[bitflags(non_exhaustive)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+let flags = Flags::from_bits(0xff).unwrap();
+
+assert_eq!(0xff, Flags::all().bits());
+assert_eq!("Flags(A | B | 0xfc)", format!("{:?}", flags));
+```
+
+# Deriving `Display`
+
+The `#[bitflags(derive_display)]` attribute on a flags type implements `Display` for it, using the
+text format from the [`parser`] module. Without it, flags types don't implement `Display`, so it
+can be implemented manually instead.
+
+## Examples
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[bitflags(derive_display)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+assert_eq!("A | B", (Flags::A | Flags::B).to_string());
+```
+
+# Deriving `FromStr`
+
+The `#[bitflags(derive_from_str)]` attribute on a flags type implements `FromStr` and
+`TryFrom<&str>` for it, using [`parser::from_str`]. Errors are reported as a
+[`parser::ParseError`].
+
+## Examples
+
+```
+# use bitflags::bitflags;
+use std::convert::TryFrom;
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(derive_from_str)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+assert_eq!(Flags::A | Flags::B, "A | B".parse::<Flags>().unwrap());
+assert_eq!(Flags::A, Flags::try_from("A").unwrap());
+assert!("C".parse::<Flags>().is_err());
+```
+
+# Deriving `TryFrom` for the bits type
+
+The `#[bitflags(derive_try_from_bits)]` attribute on a flags type implements `TryFrom<$T>` for it,
+where `$T` is its bits type, using [`Flags::from_bits`]. This is useful for checking raw bits,
+like a value received over FFI, that must only contain known bits. Errors are reported as an
+[`UnknownBits`], with the bits that failed to convert and the unknown bits they contain.
+
+The implementation isn't generated by default because it would conflict with any
+`From<$T>` implementation on the flags type.
+
+## Examples
+
+```
+# use bitflags::bitflags;
+use std::convert::TryFrom;
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(derive_try_from_bits)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+assert_eq!(Flags::A | Flags::B, Flags::try_from(0b11).unwrap());
+
+let err = Flags::try_from(0b1001).unwrap_err();
+assert_eq!(0b1000, *err.unknown_bits());
+```
+
+# Denying overlapping flags
+
+The `#[bitflags(deny_overlap)]` attribute on a flags type checks that no two named single-bit flags
+use the same bit, which usually means one of their values has a typo. Multi-bit flags are exempt,
+since they're expected to overlap with other flags. The check runs at compile time, and fails
+with an error naming the overlapping flag. It needs Rust 1.57 or later.
+
+## Examples
+
+```compile_fail
+# use bitflags::bitflags;
+bitflags! {
+    #[bitflags(deny_overlap)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        // Error: the flag `C` uses the same bit as another single-bit flag
+        const C = 1 << 1;
+    }
+}
+```
+
+# Converting to and from `bool` arrays
+
+The `#[bitflags(bool_array)]` attribute on a flags type generates methods to convert flags values
+to and from an array with one `bool` per named flag, like a checkbox for each flag in a UI:
+
+- `const COUNT: usize`: the number of named flags, and the length of the arrays.
+- `fn as_bools(&self) -> [bool; COUNT]`: whether each named flag is contained in the flags value.
+- `fn from_bools(bools: [bool; COUNT]) -> Self`: the union of the named flags that are `true`.
+
+The elements of the array are in the same order that flags are defined. Each named flag has its
+own element, even if it has no bits set or overlaps with other flags, so converting a flags
+value to an array and back may set more bits than it started with. Unnamed flags don't have an
+element, and unknown bits are dropped.
+
+## Examples
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[bitflags(bool_array)]
+    #[derive(Debug, PartialEq, Eq)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+    }
+}
+
+assert_eq!(3, Flags::COUNT);
+assert_eq!([true, false, true], (Flags::A | Flags::C).as_bools());
+assert_eq!(Flags::B, Flags::from_bools([false, true, false]));
+```
+
+# Documenting flags in a table
+
+The `#[bitflags(doc_table)]` attribute on a flags type appends a table to its documentation, with
+the name, value, and doc comments of each flag. The table is generated from the declaration, so it
+stays in sync with the flags as they change. Values are shown as they're written in the declaration,
+and flags are listed even if they're disabled by a `#[cfg]` attribute.
+
+## Examples
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    /// The permissions of a file.
+    #[bitflags(doc_table)]
+    pub struct Permissions: u8 {
+        /// The file can be read.
+        const READ = 1;
+        /// The file can be written.
+        const WRITE = 1 << 1;
+    }
+}
+```
+
+# Controlling inlining
+
+The methods generated for a flags type are `#[inline]` by default. The `#[bitflags(inline = "always")]`
+and `#[bitflags(inline = "never")]` attributes replace that hint with `#[inline(always)]` or
+`#[inline(never)]` on the methods that loop over the flags of the type: `from_bits_truncate`,
+`from_name`, `iter`, and `Debug::fmt`. This can reduce code size for types with many flags, or
+favor speed for small ones, but is only worth changing after profiling.
+
+## Examples
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[bitflags(inline = "never")]
+    #[derive(Debug, PartialEq, Eq)]
+    struct Flags: u64 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+assert_eq!(Flags::A, Flags::from_name("A").unwrap());
+```
+
+# Showing bits in `Debug`
+
+The `Debug` format of a flags type writes the names of contained flags, with any remaining bits
+as a hex number. The `#[bitflags(debug = "names_and_bits")]` attribute also writes the raw bits
+of the whole flags value after the names, so they don't need to be formatted separately. An
+empty flags value is still written as `0x0`. The default is `#[bitflags(debug = "names")]`.
+
+## Examples
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[bitflags(debug = "names_and_bits")]
+    #[derive(Debug)]
+    struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+assert_eq!("Flags(A | B = 0x3)", format!("{:?}", Flags::A | Flags::B));
+assert_eq!("Flags(A | 0x8 = 0x9)", format!("{:?}", Flags::from_bits_retain(0b1001)));
+```
+
+# Combining `#[bitflags]` attributes
+
+Multiple `#[bitflags]` attributes can be combined:
+
+```
+# use bitflags::bitflags;
+bitflags! {
+    #[bitflags(non_exhaustive, derive_display)]
+    struct Flags: u8 {
+        const A = 1;
+    }
+}
+
+assert_eq!("A | 0x2", Flags::from_bits(0b11).unwrap().to_string());
+```
+
+No other `#[bitflags]` attributes are supported. They're only supported in `struct` mode.
 */
 #[macro_export]
 macro_rules! bitflags {
     (
-        $(#[$outer:meta])*
+        $(#[$($outer:tt)*])*
         $vis:vis struct $BitFlags:ident: $T:ty {
             $(
                 $(#[$inner:ident $($args:tt)*])*
@@ -456,135 +789,1167 @@ macro_rules! bitflags {
 
         $($t:tt)*
     ) => {
-        // Declared in the scope of the `bitflags!` call
-        // This type appears in the end-user's API
-        $crate::__declare_public_bitflags! {
-            $(#[$outer])*
-            $vis struct $BitFlags
+        // `#[bitflags]` attributes are handled here instead of being forwarded to the struct
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$(#[$($outer)*])*],
+                processed: [],
+            },
+            flags: [],
+            decl: {
+                $vis struct $BitFlags: $T {
+                    $(
+                        $(#[$inner $($args)*])*
+                        const $Flag = $value;
+                    )*
+                }
+            },
+        }
+
+        $crate::bitflags! {
+            $($t)*
+        }
+    };
+    (
+        $(#[$outer:meta])*
+        impl $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt = $value:expr;
+            )*
+        }
+
+        $($t:tt)*
+    ) => {
+        $crate::__impl_public_bitflags_consts! {
+            $BitFlags: $T {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag = $value;
+                )*
+            }
+        }
+
+        #[allow(
+            dead_code,
+            deprecated,
+            unused_doc_comments,
+            unused_attributes,
+            unused_mut,
+            unused_imports,
+            non_upper_case_globals,
+            clippy::assign_op_pattern,
+            clippy::iter_without_into_iter,
+            clippy::self_named_constructors,
+        )]
+        const _: () = {
+            $crate::__impl_public_bitflags! {
+                inline: inline,
+                $(#[$outer])*
+                $BitFlags: $T, $BitFlags {
+                    $(
+                        $(#[$inner $($args)*])*
+                        const $Flag = $value;
+                    )*
+                }
+            }
+
+            $crate::__impl_public_bitflags_ops! {
+                $BitFlags
+            }
+
+            $crate::__impl_public_bitflags_iter! {
+                inline: inline,
+                $BitFlags: $T, $BitFlags
+            }
+        };
+
+        $crate::bitflags! {
+            $($t)*
+        }
+    };
+    // Flags that aren't all terminated by `;` are normalized before being handled by the arms above
+    (
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $($flags:tt)*
+        }
+
+        $($t:tt)*
+    ) => {
+        $crate::__bitflags_flags! {
+            decl: {
+                $(#[$($outer)*])*
+                $vis struct $BitFlags: $T
+            },
+            unprocessed: {
+                $($flags)*
+            },
+            processed: [],
+            rest: {
+                $($t)*
+            },
+        }
+    };
+    (
+        $(#[$($outer:tt)*])*
+        impl $BitFlags:ident: $T:ty {
+            $($flags:tt)*
+        }
+
+        $($t:tt)*
+    ) => {
+        $crate::__bitflags_flags! {
+            decl: {
+                $(#[$($outer)*])*
+                impl $BitFlags: $T
+            },
+            unprocessed: {
+                $($flags)*
+            },
+            processed: [],
+            rest: {
+                $($t)*
+            },
+        }
+    };
+    () => {};
+}
+
+/// A token-tree muncher that terminates each flag in the input to `bitflags!` with `;`.
+///
+/// This macro is only called when a declaration doesn't already use `;` for all its flags,
+/// so the common form doesn't pay for the extra recursion.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bitflags_flags {
+    // A flag terminated by `;`
+    (
+        decl: { $($decl:tt)* },
+        unprocessed: {
+            $(#[$inner:ident $($args:tt)*])*
+            const $Flag:tt = $value:expr;
+            $($unprocessed:tt)*
+        },
+        processed: [$($processed:tt)*],
+        rest: { $($t:tt)* },
+    ) => {
+        $crate::__bitflags_flags! {
+            decl: { $($decl)* },
+            unprocessed: {
+                $($unprocessed)*
+            },
+            processed: [
+                $($processed)*
+                $(#[$inner $($args)*])*
+                const $Flag = $value;
+            ],
+            rest: { $($t)* },
+        }
+    };
+    // A flag separated by `,`
+    (
+        decl: { $($decl:tt)* },
+        unprocessed: {
+            $(#[$inner:ident $($args:tt)*])*
+            const $Flag:tt = $value:expr,
+            $($unprocessed:tt)*
+        },
+        processed: [$($processed:tt)*],
+        rest: { $($t:tt)* },
+    ) => {
+        $crate::__bitflags_flags! {
+            decl: { $($decl)* },
+            unprocessed: {
+                $($unprocessed)*
+            },
+            processed: [
+                $($processed)*
+                $(#[$inner $($args)*])*
+                const $Flag = $value;
+            ],
+            rest: { $($t)* },
+        }
+    };
+    // A flag followed by something other than `;` or `,`
+    (
+        decl: { $($decl:tt)* },
+        unprocessed: {
+            $(#[$inner:ident $($args:tt)*])*
+            const $Flag:tt = $($unprocessed:tt)*
+        },
+        processed: [$($processed:tt)*],
+        rest: { $($t:tt)* },
+    ) => {
+        $crate::__private::core::compile_error!(
+            $crate::__private::core::concat!(
+                "expected `;` or `,` after the value of flag `",
+                $crate::__private::core::stringify!($Flag),
+                "`",
+            )
+        );
+    };
+    // All flags are terminated
+    (
+        decl: { $($decl:tt)* },
+        unprocessed: {},
+        processed: [$($processed:tt)*],
+        rest: { $($t:tt)* },
+    ) => {
+        $crate::bitflags! {
+            $($decl)* {
+                $($processed)*
+            }
+
+            $($t)*
+        }
+    };
+}
+
+/// Generate a flags type in `struct` mode.
+///
+/// This macro is called by `bitflags!` once any `#[bitflags]` attributes have been handled.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bitflags_struct {
+    // Apply the next option
+    // `inline(..)`: The inline hint to use on iteration-heavy methods
+    (
+        options: [inline($($mode:tt)*) $($options:tt)*],
+        inline: [],
+        debug: [$($debug:tt)*],
+        databake: [$($databake:tt)*],
+        $($decl:tt)*
+    ) => {
+        $crate::__bitflags_struct! {
+            options: [$($options)*],
+            inline: [inline($($mode)*)],
+            debug: [$($debug)*],
+            databake: [$($databake)*],
+            $($decl)*
+        }
+    };
+    (
+        options: [inline($($mode:tt)*) $($options:tt)*],
+        inline: [$($inline:tt)+],
+        debug: [$($debug:tt)*],
+        databake: [$($databake:tt)*],
+        $($decl:tt)*
+    ) => {
+        $crate::__private::core::compile_error!(
+            "the `inline` `bitflags` attribute can only be used once"
+        );
+    };
+    // Apply the next option
+    // `debug(..)`: The format used by `Debug`
+    (
+        options: [debug($mode:ident) $($options:tt)*],
+        inline: [$($inline:tt)*],
+        debug: [],
+        databake: [$($databake:tt)*],
+        $($decl:tt)*
+    ) => {
+        $crate::__bitflags_struct! {
+            options: [$($options)*],
+            inline: [$($inline)*],
+            debug: [$mode],
+            databake: [$($databake)*],
+            $($decl)*
+        }
+    };
+    (
+        options: [debug($mode:ident) $($options:tt)*],
+        inline: [$($inline:tt)*],
+        debug: [$($debug:tt)+],
+        databake: [$($databake:tt)*],
+        $($decl:tt)*
+    ) => {
+        $crate::__private::core::compile_error!(
+            "the `debug` `bitflags` attribute can only be used once"
+        );
+    };
+    // Apply the next option
+    // `databake(..)`: The path of the module to bake the flags type with
+    (
+        options: [databake($path:literal) $($options:tt)*],
+        inline: [$($inline:tt)*],
+        debug: [$($debug:tt)*],
+        databake: [],
+        $($decl:tt)*
+    ) => {
+        $crate::__bitflags_struct! {
+            options: [$($options)*],
+            inline: [$($inline)*],
+            debug: [$($debug)*],
+            databake: [$path],
+            $($decl)*
+        }
+    };
+    (
+        options: [databake($path:literal) $($options:tt)*],
+        inline: [$($inline:tt)*],
+        debug: [$($debug:tt)*],
+        databake: [$($databake:tt)+],
+        $($decl:tt)*
+    ) => {
+        $crate::__private::core::compile_error!(
+            "the `databake_path` `bitflags` attribute can only be used once"
+        );
+    };
+    // Once all options are applied, use the defaults for any that weren't set
+    (
+        options: [],
+        inline: [],
+        debug: [$($debug:tt)*],
+        databake: [$($databake:tt)*],
+        $($decl:tt)*
+    ) => {
+        $crate::__bitflags_struct! {
+            options: [],
+            inline: [inline],
+            debug: [$($debug)*],
+            databake: [$($databake)*],
+            $($decl)*
+        }
+    };
+    (
+        options: [],
+        inline: [$($inline:tt)+],
+        debug: [],
+        databake: [$($databake:tt)*],
+        $($decl:tt)*
+    ) => {
+        $crate::__bitflags_struct! {
+            options: [],
+            inline: [$($inline)+],
+            debug: [names],
+            databake: [$($databake)*],
+            $($decl)*
+        }
+    };
+    (
+        options: [],
+        inline: [$inline:meta],
+        debug: [$debug:ident],
+        databake: [$($databake:tt)*],
+        $(#[$outer:meta])*
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        // Declared in the scope of the `bitflags!` call
+        // This type appears in the end-user's API
+        $crate::__declare_public_bitflags! {
+            $(#[$outer])*
+            $vis struct $BitFlags
+        }
+
+        // Workaround for: https://github.com/bitflags/bitflags/issues/320
+        $crate::__impl_public_bitflags_consts! {
+            $BitFlags: $T {
+                $(
+                    $(#[$inner $($args)*])*
+                    const $Flag = $value;
+                )*
+            }
+        }
+
+        #[allow(
+            dead_code,
+            deprecated,
+            unused_doc_comments,
+            unused_attributes,
+            unused_mut,
+            unused_imports,
+            non_upper_case_globals,
+            clippy::assign_op_pattern,
+            clippy::indexing_slicing,
+            clippy::same_name_method,
+            clippy::iter_without_into_iter,
+            clippy::self_named_constructors,
+        )]
+        const _: () = {
+            // Declared in a "hidden" scope that can't be reached directly
+            // These types don't appear in the end-user's API
+            $crate::__declare_internal_bitflags! {
+                $vis struct InternalBitFlags: $T
+            }
+
+            $crate::__impl_internal_bitflags! {
+                inline: $inline,
+                debug: $debug,
+                InternalBitFlags: $T, $BitFlags {
+                    $(
+                        $(#[$inner $($args)*])*
+                        const $Flag = $value;
+                    )*
+                }
+            }
+
+            // This is where new library trait implementations can be added
+            $crate::__impl_external_bitflags! {
+                databake: [$($databake)*],
+                InternalBitFlags: $T, $BitFlags {
+                    $(
+                        $(#[$inner $($args)*])*
+                        const $Flag;
+                    )*
+                }
+            }
+
+            $crate::__impl_public_bitflags_forward! {
+                inline: $inline,
+                $BitFlags: $T, InternalBitFlags
+            }
+
+            $crate::__impl_public_bitflags_ops! {
+                $BitFlags
+            }
+
+            $crate::__impl_public_bitflags_iter! {
+                inline: $inline,
+                $BitFlags: $T, $BitFlags
+            }
+        };
+    };
+}
+
+/// Handle `#[bitflags]` attributes on a flags type in `struct` mode.
+///
+/// Other attributes are forwarded to the generated struct.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bitflags_struct_attrs {
+    // Process the next attribute on the struct
+    // `bitflags(a, b)`: Split into separate `bitflags(a)` and `bitflags(b)` attributes
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags($first:ident $(= $first_value:tt)? $(, $rest:ident $(= $rest_value:tt)?)+ $(,)?)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [
+                    #[bitflags($first $(= $first_value)?)]
+                    $(#[bitflags($rest $(= $rest_value)?)])+
+                    $($attrs_rest)*
+                ],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)*],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(non_exhaustive)`: Consider all bits known, as if an unnamed `!0` flag was defined
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(non_exhaustive)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* non_exhaustive],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(derive_display)`: Implement `Display` using the text format in `parser`
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(derive_display)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* derive_display],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(derive_from_str)`: Implement `FromStr` and `TryFrom<&str>` using `parser::from_str`
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(derive_from_str)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* derive_from_str],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(derive_try_from_bits)`: Implement `TryFrom<$T>` using `from_bits`
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(derive_try_from_bits)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* derive_try_from_bits],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(deny_overlap)`: Fail to compile if any single-bit flags share a bit
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(deny_overlap)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* deny_overlap],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(bool_array)`: Convert to and from an array of `bool`s with one per named flag
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(bool_array)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* bool_array],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(doc_table)`: Append a table of flags to the documentation of the flags type
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(doc_table)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* doc_table],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(inline = "always")`: Use `#[inline(always)]` on iteration-heavy generated methods
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(inline = "always")]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* inline(always)],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(inline = "never")`: Use `#[inline(never)]` on iteration-heavy generated methods
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(inline = "never")]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* inline(never)],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(debug = "names")`: Use the default format for `Debug`, with the names of flags
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(debug = "names")]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* debug(names)],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(debug = "names_and_bits")`: Follow the names of flags in `Debug` with the raw bits
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(debug = "names_and_bits")]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* debug(names_and_bits)],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(databake_path = "..")`: Implement `Bake` through the given module path
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags(databake_path = $path:literal)]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)* databake($path)],
+            decl: { $($decl)* },
+        }
+    };
+    // Process the next attribute on the struct
+    // `bitflags(..)`: Any other `bitflags` attribute is an error
+    (
+        attrs: {
+            unprocessed: [
+                #[bitflags $($args:tt)*]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__private::core::compile_error!($crate::__private::core::concat!(
+            "unrecognized `bitflags` attribute `#[bitflags",
+            $crate::__private::core::stringify!($($args)*),
+            "]`, expected one of `non_exhaustive`, `derive_display`, `derive_from_str`, `derive_try_from_bits`, `deny_overlap`, `bool_array`, `doc_table`, `inline = \"always\"`, `inline = \"never\"`, `debug = \"names\"`, `debug = \"names_and_bits\"`, or `databake_path = \"..\"`"
+        ));
+    };
+    // Process the next attribute on the struct
+    // `$other`: The attribute is forwarded to the struct
+    (
+        attrs: {
+            unprocessed: [
+                #[$($other:tt)*]
+                $($attrs_rest:tt)*
+            ],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($flags:tt)*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [$($attrs_rest)*],
+                processed: [$($processed)* #[$($other)*]],
+            },
+            flags: [$($flags)*],
+            decl: { $($decl)* },
+        }
+    };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `non_exhaustive`: Add an unnamed flag with all bits set
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [non_exhaustive $($flags:tt)*],
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
+                $($body:tt)*
+            }
+        },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)*],
+            decl: {
+                $vis struct $BitFlags: $T {
+                    $($body)*
+
+                    const _ = <$T as $crate::Bits>::ALL;
+                }
+            },
+        }
+    };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `derive_display`: Implement `Display` alongside the flags type
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [derive_display $($flags:tt)*],
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
+                $($body:tt)*
+            }
+        },
+    ) => {
+        impl $crate::__private::core::fmt::Display for $BitFlags {
+            fn fmt(&self, f: &mut $crate::__private::core::fmt::Formatter<'_>) -> $crate::__private::core::fmt::Result {
+                $crate::parser::to_writer(self, f)
+            }
+        }
+
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)*],
+            decl: {
+                $vis struct $BitFlags: $T {
+                    $($body)*
+                }
+            },
+        }
+    };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `derive_from_str`: Implement `FromStr` and `TryFrom<&str>` alongside the flags type
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [derive_from_str $($flags:tt)*],
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
+                $($body:tt)*
+            }
+        },
+    ) => {
+        impl $crate::__private::core::str::FromStr for $BitFlags {
+            type Err = $crate::parser::ParseError;
+
+            fn from_str(s: &str) -> $crate::__private::core::result::Result<Self, Self::Err> {
+                $crate::parser::from_str(s)
+            }
+        }
+
+        impl<'a> $crate::__private::core::convert::TryFrom<&'a str> for $BitFlags {
+            type Error = $crate::parser::ParseError;
+
+            fn try_from(s: &'a str) -> $crate::__private::core::result::Result<Self, Self::Error> {
+                $crate::parser::from_str(s)
+            }
+        }
+
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)*],
+            decl: {
+                $vis struct $BitFlags: $T {
+                    $($body)*
+                }
+            },
+        }
+    };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `derive_try_from_bits`: Implement `TryFrom<$T>` alongside the flags type
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [derive_try_from_bits $($flags:tt)*],
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
+                $($body:tt)*
+            }
+        },
+    ) => {
+        impl $crate::__private::core::convert::TryFrom<$T> for $BitFlags {
+            type Error = $crate::UnknownBits<$T>;
+
+            fn try_from(bits: $T) -> $crate::__private::core::result::Result<Self, Self::Error> {
+                match Self::from_bits(bits) {
+                    $crate::__private::core::option::Option::Some(flags) => {
+                        $crate::__private::core::result::Result::Ok(flags)
+                    }
+                    $crate::__private::core::option::Option::None => {
+                        $crate::__private::core::result::Result::Err($crate::UnknownBits::new(
+                            bits,
+                            bits & !Self::all().bits(),
+                        ))
+                    }
+                }
+            }
         }
 
-        // Workaround for: https://github.com/bitflags/bitflags/issues/320
-        $crate::__impl_public_bitflags_consts! {
-            $BitFlags: $T {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)*],
+            decl: {
+                $vis struct $BitFlags: $T {
+                    $($body)*
+                }
+            },
+        }
+    };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `deny_overlap`: Check that no single-bit flags share a bit in a `const` block
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [deny_overlap $($flags:tt)*],
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
                 $(
-                    $(#[$inner $($args)*])*
-                    const $Flag = $value;
+                    $(#[$inner:ident $($args:tt)*])*
+                    const $Flag:tt = $value:expr;
                 )*
             }
-        }
-
+        },
+    ) => {
         #[allow(
             dead_code,
             deprecated,
-            unused_doc_comments,
-            unused_attributes,
             unused_mut,
-            unused_imports,
-            non_upper_case_globals,
-            clippy::assign_op_pattern,
-            clippy::indexing_slicing,
-            clippy::same_name_method,
-            clippy::iter_without_into_iter,
+            unused_variables,
         )]
         const _: () = {
-            // Declared in a "hidden" scope that can't be reached directly
-            // These types don't appear in the end-user's API
-            $crate::__declare_internal_bitflags! {
-                $vis struct InternalBitFlags: $T
-            }
+            let mut seen = <$T as $crate::Bits>::EMPTY;
 
-            $crate::__impl_internal_bitflags! {
-                InternalBitFlags: $T, $BitFlags {
+            $(
+                $crate::__bitflags_flag!({
+                    name: $Flag,
+                    named: {
+                        $crate::__bitflags_expr_safe_attrs!(
+                            $(#[$inner $($args)*])*
+                            {{
+                                let bits = $BitFlags::$Flag.bits();
+
+                                // Multi-bit flags are expected to overlap with other flags
+                                if bits.count_ones() == 1 {
+                                    if seen & bits != <$T as $crate::Bits>::EMPTY {
+                                        $crate::__private::core::panic!($crate::__private::core::concat!(
+                                            "the flag `",
+                                            $crate::__private::core::stringify!($Flag),
+                                            "` uses the same bit as another single-bit flag"
+                                        ));
+                                    }
+
+                                    seen = seen | bits;
+                                }
+                            }}
+                        );
+                    },
+                    unnamed: {},
+                });
+            )*
+        };
+
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)*],
+            decl: {
+                $vis struct $BitFlags: $T {
                     $(
                         $(#[$inner $($args)*])*
                         const $Flag = $value;
                     )*
                 }
+            },
+        }
+    };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `bool_array`: Add methods converting to and from an array of `bool`s alongside the flags type
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [bool_array $($flags:tt)*],
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
+                $($body:tt)*
             }
+        },
+    ) => {
+        #[allow(dead_code)]
+        impl $BitFlags {
+            /// The number of named flags, which is the length of the arrays used by
+            /// `as_bools` and `from_bools`.
+            pub const COUNT: usize = {
+                let flags = <$BitFlags as $crate::Flags>::FLAGS;
+
+                let mut count = 0;
+                let mut i = 0;
+                while i < flags.len() {
+                    if flags[i].is_named() {
+                        count += 1;
+                    }
+
+                    i += 1;
+                }
 
-            // This is where new library trait implementations can be added
-            $crate::__impl_external_bitflags! {
-                InternalBitFlags: $T, $BitFlags {
-                    $(
-                        $(#[$inner $($args)*])*
-                        const $Flag;
-                    )*
+                count
+            };
+
+            /// Get whether each named flag is contained in this flags value.
+            ///
+            /// The array has one element per named flag, in the same order as `FLAGS`.
+            #[inline]
+            pub const fn as_bools(&self) -> [bool; $BitFlags::COUNT] {
+                let flags = <$BitFlags as $crate::Flags>::FLAGS;
+
+                let mut bools = [false; $BitFlags::COUNT];
+                let mut slot = 0;
+                let mut i = 0;
+                while i < flags.len() {
+                    if flags[i].is_named() {
+                        bools[slot] = self.contains($BitFlags::from_bits_retain(flags[i].value().bits()));
+                        slot += 1;
+                    }
+
+                    i += 1;
                 }
-            }
 
-            $crate::__impl_public_bitflags_forward! {
-                $BitFlags: $T, InternalBitFlags
+                bools
             }
 
-            $crate::__impl_public_bitflags_ops! {
-                $BitFlags
-            }
+            /// Get a flags value from whether each named flag is contained.
+            ///
+            /// The array has one element per named flag, in the same order as `FLAGS`.
+            /// The result is the union of the flags whose elements are `true`.
+            #[inline]
+            pub const fn from_bools(bools: [bool; $BitFlags::COUNT]) -> Self {
+                let flags = <$BitFlags as $crate::Flags>::FLAGS;
+
+                let mut bits = <$T as $crate::Bits>::EMPTY;
+                let mut slot = 0;
+                let mut i = 0;
+                while i < flags.len() {
+                    if flags[i].is_named() {
+                        if bools[slot] {
+                            bits |= flags[i].value().bits();
+                        }
+
+                        slot += 1;
+                    }
+
+                    i += 1;
+                }
 
-            $crate::__impl_public_bitflags_iter! {
-                $BitFlags: $T, $BitFlags
+                $BitFlags::from_bits_retain(bits)
             }
-        };
+        }
 
-        $crate::bitflags! {
-            $($t)*
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)*],
+            decl: {
+                $vis struct $BitFlags: $T {
+                    $($body)*
+                }
+            },
         }
     };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `doc_table`: Add a doc attribute with an HTML table of flags to the declaration
     (
-        $(#[$outer:meta])*
-        impl $BitFlags:ident: $T:ty {
-            $(
-                $(#[$inner:ident $($args:tt)*])*
-                const $Flag:tt = $value:expr;
-            )*
-        }
-
-        $($t:tt)*
-    ) => {
-        $crate::__impl_public_bitflags_consts! {
-            $BitFlags: $T {
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [doc_table $($flags:tt)*],
+        decl: {
+            $vis:vis struct $BitFlags:ident: $T:ty {
                 $(
-                    $(#[$inner $($args)*])*
-                    const $Flag = $value;
+                    $(#[$inner:ident $($args:tt)*])*
+                    const $Flag:tt = $value:expr;
                 )*
             }
-        }
-
-        #[allow(
-            dead_code,
-            deprecated,
-            unused_doc_comments,
-            unused_attributes,
-            unused_mut,
-            unused_imports,
-            non_upper_case_globals,
-            clippy::assign_op_pattern,
-            clippy::iter_without_into_iter,
-        )]
-        const _: () = {
-            $crate::__impl_public_bitflags! {
-                $(#[$outer])*
-                $BitFlags: $T, $BitFlags {
+        },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [
+                    $($processed)*
+                    #[doc = $crate::__bitflags_doc_table! {
+                        $(
+                            $(#[$inner $($args)*])*
+                            const $Flag = $value;
+                        )*
+                    }]
+                ],
+            },
+            flags: [$($flags)*],
+            decl: {
+                $vis struct $BitFlags: $T {
                     $(
                         $(#[$inner $($args)*])*
                         const $Flag = $value;
                     )*
                 }
-            }
-
-            $crate::__impl_public_bitflags_ops! {
-                $BitFlags
-            }
-
-            $crate::__impl_public_bitflags_iter! {
-                $BitFlags: $T, $BitFlags
-            }
-        };
-
-        $crate::bitflags! {
-            $($t)*
+            },
+        }
+    };
+    // Once all attributes and flags are processed, generate the flags type
+    // `$option(..)`: Options like `inline(..)` are passed to the generated flags type
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$($option:ident($($value:tt)*))*],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct! {
+            options: [$($option($($value)*))*],
+            inline: [],
+            debug: [],
+            databake: [],
+            $($processed)*
+            $($decl)*
+        }
+    };
+    // Once all attributes are processed, apply the next flag to the declaration
+    // `$option(..)`: Options are used when generating the flags type, so they're applied last
+    (
+        attrs: {
+            unprocessed: [],
+            processed: [$($processed:tt)*],
+        },
+        flags: [$option:ident($($value:tt)*) $($flags:tt)+],
+        decl: { $($decl:tt)* },
+    ) => {
+        $crate::__bitflags_struct_attrs! {
+            attrs: {
+                unprocessed: [],
+                processed: [$($processed)*],
+            },
+            flags: [$($flags)+ $option($($value)*)],
+            decl: { $($decl)* },
         }
     };
-    () => {};
 }
 
 /// Implement functions on bitflags types.
@@ -595,28 +1960,44 @@ macro_rules! bitflags {
 #[doc(hidden)]
 macro_rules! __impl_bitflags {
     (
+        inline: $inline:meta,
         $(#[$outer:meta])*
         $PublicBitFlags:ident: $T:ty {
             fn empty() $empty:block
             fn all() $all:block
+            fn covers_all_bits() $covers_all_bits:block
+            fn max_bit() $max_bit:block
             fn bits($bits0:ident) $bits:block
+            fn into_bits($into_bits0:ident) $into_bits:block
             fn from_bits($from_bits0:ident) $from_bits:block
             fn from_bits_truncate($from_bits_truncate0:ident) $from_bits_truncate:block
             fn from_bits_retain($from_bits_retain0:ident) $from_bits_retain:block
+            fn from_bits_with_unknown($from_bits_with_unknown0:ident) $from_bits_with_unknown:block
             fn from_name($from_name0:ident) $from_name:block
             fn is_empty($is_empty0:ident) $is_empty:block
             fn is_all($is_all0:ident) $is_all:block
             fn intersects($intersects0:ident, $intersects1:ident) $intersects:block
             fn contains($contains0:ident, $contains1:ident) $contains:block
+            fn is_subset($is_subset0:ident, $is_subset1:ident) $is_subset:block
+            fn is_superset($is_superset0:ident, $is_superset1:ident) $is_superset:block
+            fn is_disjoint($is_disjoint0:ident, $is_disjoint1:ident) $is_disjoint:block
+            fn truncate($truncate0:ident) $truncate:block
             fn insert($insert0:ident, $insert1:ident) $insert:block
             fn remove($remove0:ident, $remove1:ident) $remove:block
             fn toggle($toggle0:ident, $toggle1:ident) $toggle:block
+            fn toggle_all($toggle_all0:ident) $toggle_all:block
             fn set($set0:ident, $set1:ident, $set2:ident) $set:block
             fn intersection($intersection0:ident, $intersection1:ident) $intersection:block
             fn union($union0:ident, $union1:ident) $union:block
             fn difference($difference0:ident, $difference1:ident) $difference:block
             fn symmetric_difference($symmetric_difference0:ident, $symmetric_difference1:ident) $symmetric_difference:block
             fn complement($complement0:ident) $complement:block
+            fn complement_retain($complement_retain0:ident) $complement_retain:block
+            fn with($with0:ident, $with1:ident) $with:block
+            fn without($without0:ident, $without1:ident) $without:block
+            fn with_set($with_set0:ident, $with_set1:ident, $with_set2:ident) $with_set:block
+            fn cmp_by_bits($cmp_by_bits0:ident, $cmp_by_bits1:ident) $cmp_by_bits:block
+            fn cmp_by_count($cmp_by_count0:ident, $cmp_by_count1:ident) $cmp_by_count:block
         }
     ) => {
         #[allow(dead_code, deprecated, unused_attributes)]
@@ -634,6 +2015,27 @@ macro_rules! __impl_bitflags {
                 $all
             }
 
+            /// Whether every bit of the bits type is a known bit.
+            ///
+            /// This is `true` when [`all`](#method.all) has the same bits as `Bits::ALL`, such as
+            /// when the flags type has an unnamed flag with all bits set. Flags types that are
+            /// meant to use every bit can check this at compile time with
+            /// `const _: () = assert!(Flags::covers_all_bits());`.
+            #[inline]
+            pub const fn covers_all_bits() -> bool {
+                $covers_all_bits
+            }
+
+            /// Get the index of the highest bit set in any named flag.
+            ///
+            /// This method will return `None` if no named flags have any bits set. Unnamed flags
+            /// aren't considered. It can be checked at compile time to make sure all named flags fit
+            /// in a fixed number of bits, like `assert!(matches!(Flags::max_bit(), Some(bit) if bit < 12))`.
+            #[inline]
+            pub const fn max_bit() -> $crate::__private::core::option::Option<u32> {
+                $max_bit
+            }
+
             /// Get the underlying bits value.
             ///
             /// The returned value is exactly the bits set in this flags value.
@@ -643,6 +2045,15 @@ macro_rules! __impl_bitflags {
                 $bits
             }
 
+            /// Get the underlying bits value, consuming the flags value.
+            ///
+            /// This method is like [`bits`](#method.bits), but takes `self` by value.
+            #[inline]
+            pub const fn into_bits(self) -> $T {
+                let $into_bits0 = self;
+                $into_bits
+            }
+
             /// Convert from a bits value.
             ///
             /// This method will return `None` if any unknown bits are set.
@@ -653,7 +2064,7 @@ macro_rules! __impl_bitflags {
             }
 
             /// Convert from a bits value, unsetting any unknown bits.
-            #[inline]
+            #[$inline]
             pub const fn from_bits_truncate(bits: $T) -> Self {
                 let $from_bits_truncate0 = bits;
                 $from_bits_truncate
@@ -666,12 +2077,23 @@ macro_rules! __impl_bitflags {
                 $from_bits_retain
             }
 
+            /// Convert from a bits value exactly, also returning any unknown bits.
+            ///
+            /// The returned flags value is the same as [`from_bits_retain`](#method.from_bits_retain).
+            /// The returned bits value is the set of unknown bits, which will be empty if
+            /// [`from_bits`](#method.from_bits) would return `Some`.
+            #[inline]
+            pub const fn from_bits_with_unknown(bits: $T) -> (Self, $T) {
+                let $from_bits_with_unknown0 = bits;
+                $from_bits_with_unknown
+            }
+
             /// Get a flags value with the bits of a flag with the given name set.
             ///
             /// This method will return `None` if `name` is empty or doesn't
             /// correspond to any named flag.
-            #[inline]
-            pub fn from_name(name: &str) -> $crate::__private::core::option::Option<Self> {
+            #[$inline]
+            pub const fn from_name(name: &str) -> $crate::__private::core::option::Option<Self> {
                 let $from_name0 = name;
                 $from_name
             }
@@ -706,6 +2128,43 @@ macro_rules! __impl_bitflags {
                 $contains
             }
 
+            /// Whether all set bits in a source flags value are also set in a target flags value.
+            ///
+            /// This method is equivalent to `other.contains(self)`.
+            #[inline]
+            pub const fn is_subset(&self, other: Self) -> bool {
+                let $is_subset0 = self;
+                let $is_subset1 = other;
+                $is_subset
+            }
+
+            /// Whether all set bits in a target flags value are also set in a source flags value.
+            ///
+            /// This method is equivalent to `self.contains(other)`.
+            #[inline]
+            pub const fn is_superset(&self, other: Self) -> bool {
+                let $is_superset0 = self;
+                let $is_superset1 = other;
+                $is_superset
+            }
+
+            /// Whether no set bits in a source flags value are also set in a target flags value.
+            ///
+            /// This method is equivalent to `!self.intersects(other)`.
+            #[inline]
+            pub const fn is_disjoint(&self, other: Self) -> bool {
+                let $is_disjoint0 = self;
+                let $is_disjoint1 = other;
+                $is_disjoint
+            }
+
+            /// Remove any unknown bits from the flags.
+            #[inline]
+            pub fn truncate(&mut self) {
+                let $truncate0 = self;
+                $truncate
+            }
+
             /// The bitwise or (`|`) of the bits in two flags values.
             #[inline]
             pub fn insert(&mut self, other: Self) {
@@ -733,6 +2192,15 @@ macro_rules! __impl_bitflags {
                 $toggle
             }
 
+            /// Flip every defined flag, removing any unknown bits.
+            ///
+            /// This method is like `complement`, except it modifies the flags value in place.
+            #[inline]
+            pub fn toggle_all(&mut self) {
+                let $toggle_all0 = self;
+                $toggle_all
+            }
+
             /// Call `insert` when `value` is `true` or `remove` when `value` is `false`.
             #[inline]
             pub fn set(&mut self, other: Self, value: bool) {
@@ -788,6 +2256,73 @@ macro_rules! __impl_bitflags {
                 let $complement0 = self;
                 $complement
             }
+
+            /// The bitwise negation (`!`) of the bits in a flags value, retaining unknown bits.
+            ///
+            /// This method is like [`complement`](#method.complement), except the result isn't
+            /// truncated, so every bit of the underlying bits value is flipped.
+            #[inline]
+            #[must_use]
+            pub const fn complement_retain(self) -> Self {
+                let $complement_retain0 = self;
+                $complement_retain
+            }
+
+            /// The bitwise or (`|`) of the bits in two flags values.
+            ///
+            /// This method is like [`insert`](#method.insert), except it consumes and returns the flags value.
+            #[inline]
+            #[must_use]
+            pub const fn with(self, other: Self) -> Self {
+                let $with0 = self;
+                let $with1 = other;
+                $with
+            }
+
+            /// The intersection of a source flags value with the complement of a target flags value (`&!`).
+            ///
+            /// This method is like [`remove`](#method.remove), except it consumes and returns the flags value.
+            #[inline]
+            #[must_use]
+            pub const fn without(self, other: Self) -> Self {
+                let $without0 = self;
+                let $without1 = other;
+                $without
+            }
+
+            /// Call `with` when `value` is `true` or `without` when `value` is `false`.
+            ///
+            /// This method is like [`set`](#method.set), except it consumes and returns the flags value.
+            #[inline]
+            #[must_use]
+            pub const fn with_set(self, other: Self, value: bool) -> Self {
+                let $with_set0 = self;
+                let $with_set1 = other;
+                let $with_set2 = value;
+                $with_set
+            }
+
+            /// Compare two flags values by their underlying bits values as numbers.
+            ///
+            /// This is the same order as `#[derive(PartialOrd, Ord)]` on the flags type. It's a total
+            /// order, but it isn't related to whether one flags value contains another.
+            #[inline]
+            pub fn cmp_by_bits(&self, other: &Self) -> $crate::__private::core::cmp::Ordering {
+                let $cmp_by_bits0 = self;
+                let $cmp_by_bits1 = other;
+                $cmp_by_bits
+            }
+
+            /// Compare two flags values by the number of bits they have set, and then by their
+            /// underlying bits values as numbers.
+            ///
+            /// A flags value always sorts before any other flags value that contains it.
+            #[inline]
+            pub fn cmp_by_count(&self, other: &Self) -> $crate::__private::core::cmp::Ordering {
+                let $cmp_by_count0 = self;
+                let $cmp_by_count1 = other;
+                $cmp_by_count
+            }
         }
     };
 }
@@ -987,6 +2522,55 @@ macro_rules! __bitflags_expr_safe_attrs {
     }
 }
 
+/// Generate an HTML table of flags as a doc string.
+///
+/// The cells are surrounded by blank lines so their contents are rendered as Markdown.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bitflags_doc_table {
+    (
+        $(
+            $(#[$inner:ident $($args:tt)*])*
+            const $Flag:tt = $value:expr;
+        )*
+    ) => {
+        $crate::__private::core::concat!(
+            "\n<table>\n<tr><th>Flag</th><th>Value</th><th>Description</th></tr>\n",
+            $(
+                "<tr><td>\n\n`",
+                $crate::__private::core::stringify!($Flag),
+                "`\n\n</td><td>\n\n`",
+                $crate::__private::core::stringify!($value),
+                "`\n\n</td><td>\n\n",
+                $crate::__bitflags_doc_text!($(#[$inner $($args)*])*),
+                "\n\n</td></tr>\n",
+            )*
+            "</table>",
+        )
+    };
+}
+
+/// Concatenate the doc attributes of a flag into a single string, ignoring any other attributes.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bitflags_doc_text {
+    (
+        #[doc = $doc:literal]
+        $($rest:tt)*
+    ) => {
+        $crate::__private::core::concat!($doc, "\n", $crate::__bitflags_doc_text!($($rest)*))
+    };
+    (
+        #[$other:ident $($args:tt)*]
+        $($rest:tt)*
+    ) => {
+        $crate::__bitflags_doc_text!($($rest)*)
+    };
+    () => {
+        ""
+    };
+}
+
 /// Implement a flag, which may be a wildcard `_`.
 #[macro_export]
 #[doc(hidden)]
@@ -4,9 +4,12 @@ Parsing flags from text.
 Format and parse a flags value as text using the following grammar:
 
 - _Flags:_ (_Whitespace_ _Flag_ _Whitespace_)`|`*
-- _Flag:_ _Name_ | _Hex Number_
+- _Flag:_ (`!` _Whitespace_)? _Value_
+- _Value:_ _Name_ | _Hex Number_ | _Binary Number_ | _Octal Number_
 - _Name:_ The name of any defined flag
-- _Hex Number_: `0x`([0-9a-fA-F])*
+- _Hex Number_: `0x`([0-9a-fA-F_])*
+- _Binary Number_: `0b`([0-1])*
+- _Octal Number_: `0o`([0-7])*
 - _Whitespace_: (\s)*
 
 As an example, this is how `Flags::A | Flags::B | 0x0c` can be represented as text:
@@ -21,16 +24,61 @@ Alternatively, it could be represented without whitespace:
 A|B|0x0C
 ```
 
+Binary and octal numbers are also accepted when parsing, so the following is equivalent too:
+
+```text
+A | B | 0b1100
+```
+
 Note that identifiers are *case-sensitive*, so the following is *not equivalent*:
 
 ```text
 a|b|0x0C
 ```
+
+Flags are parsed from left to right. A flag prefixed with `!` is removed from the flags parsed
+before it instead of being added, so the following is equivalent to `A | C`:
+
+```text
+A | B | C | !B
+```
+
+A `!` only removes the flags before it, so a leading `!B` has no effect. Flags are never written
+with a `!` prefix.
+
+The [`to_writer_with`] and [`from_str_with`] functions can be used to separate flags by
+something other than `|`.
+
+The [`from_str_qualified`] function also accepts names qualified by the name of the flags type,
+so the following is equivalent to `A | B` for a flags type called `Flags`:
+
+```text
+Flags::A | B
+```
+
+Hex numbers may contain `_` digit separators, like Rust literals, so the following is also
+equivalent:
+
+```text
+A | B | 0x0_c
+```
+
+The [`to_writer_with_options`] function can be used to write hex numbers with separators.
+
+An empty flags value is represented as an empty string. The [`to_writer_with_options`] and
+[`from_str_with_options`] functions can be used to represent it as an explicit token instead:
+
+```text
+<empty>
+```
 */
 
 #![allow(clippy::let_unit_value)]
 
-use core::fmt::{self, Write};
+use core::{
+    fmt::{self, Write},
+    ops::Range,
+};
 
 use crate::{Bits, Flags};
 
@@ -39,7 +87,101 @@ Write a flags value as text.
 
 Any bits that aren't part of a contained flag will be formatted as a hex number.
 */
-pub fn to_writer<B: Flags>(flags: &B, mut writer: impl Write) -> Result<(), fmt::Error>
+pub fn to_writer<B: Flags>(flags: &B, writer: impl Write) -> Result<(), fmt::Error>
+where
+    B::Bits: WriteHex,
+{
+    to_writer_with(flags, writer, " | ")
+}
+
+/**
+Write a flags value as text, using a custom separator between flags.
+
+Any bits that aren't part of a contained flag will be formatted as a hex number.
+
+If `separator` is empty then the names of flags will be written directly after one another,
+so the output won't be parseable.
+*/
+pub fn to_writer_with<B: Flags>(
+    flags: &B,
+    writer: impl Write,
+    separator: &str,
+) -> Result<(), fmt::Error>
+where
+    B::Bits: WriteHex,
+{
+    write_flags(flags, writer, separator, FmtOptions::new())
+}
+
+/**
+Write a flags value as text, using the given options to format any remaining bits.
+
+With the default [`FmtOptions`], this function produces the same output as [`to_writer`].
+*/
+pub fn to_writer_with_options<B: Flags>(
+    flags: &B,
+    writer: impl Write,
+    options: FmtOptions,
+) -> Result<(), fmt::Error>
+where
+    B::Bits: WriteHex,
+{
+    write_flags(flags, writer, " | ", options)
+}
+
+/**
+Options for formatting a flags value as text with [`to_writer_with_options`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FmtOptions {
+    group_hex: bool,
+    uppercase_hex: bool,
+    empty_token: Option<&'static str>,
+}
+
+impl FmtOptions {
+    /// Get the default options, which format flags values the same way as [`to_writer`].
+    pub const fn new() -> Self {
+        FmtOptions {
+            group_hex: false,
+            uppercase_hex: false,
+            empty_token: None,
+        }
+    }
+
+    /// Whether to separate every 4 digits of a hex number with `_`, like `0xf_ffff`.
+    ///
+    /// Grouped hex numbers can still be parsed by [`from_str`].
+    pub const fn group_hex(self, group_hex: bool) -> Self {
+        FmtOptions { group_hex, ..self }
+    }
+
+    /// Whether to write the digits of a hex number in uppercase, like `0xFF`.
+    pub const fn uppercase_hex(self, uppercase_hex: bool) -> Self {
+        FmtOptions {
+            uppercase_hex,
+            ..self
+        }
+    }
+
+    /// Write an empty flags value as `token`, like `<empty>`, instead of as an empty string.
+    ///
+    /// The token can be parsed by [`from_str_with_options`] with the same
+    /// [`ParseOptions::empty_token`].
+    pub const fn empty_token(self, token: &'static str) -> Self {
+        FmtOptions {
+            empty_token: Some(token),
+            ..self
+        }
+    }
+}
+
+fn write_flags<B: Flags>(
+    flags: &B,
+    mut writer: impl Write,
+    separator: &str,
+    options: FmtOptions,
+) -> Result<(), fmt::Error>
 where
     B::Bits: WriteHex,
 {
@@ -47,7 +189,7 @@ where
     //
     // A | B | 0xf6
     //
-    // The names of set flags are written in a bar-separated-format,
+    // The names of set flags are written in a separated format,
     // followed by a hex number of any remaining bits that are set
     // but don't correspond to any flags.
 
@@ -56,7 +198,7 @@ where
     let mut iter = flags.iter_names();
     for (name, _) in &mut iter {
         if !first {
-            writer.write_str(" | ")?;
+            writer.write_str(separator)?;
         }
 
         first = false;
@@ -67,16 +209,76 @@ where
     let remaining = iter.remaining().bits();
     if remaining != B::Bits::EMPTY {
         if !first {
-            writer.write_str(" | ")?;
+            writer.write_str(separator)?;
         }
 
         writer.write_str("0x")?;
-        remaining.write_hex(writer)?;
+        write_hex_with(&remaining, writer, options)?;
+    }
+    // Write an empty flags value as the explicit token, if there is one
+    else if first {
+        if let Some(token) = options.empty_token {
+            writer.write_str(token)?;
+        }
     }
 
     fmt::Result::Ok(())
 }
 
+// Write a hex number, grouping and changing the case of its digits
+fn write_hex_with(bits: &impl WriteHex, writer: impl Write, options: FmtOptions) -> fmt::Result {
+    if !options.group_hex && !options.uppercase_hex {
+        return bits.write_hex(writer);
+    }
+
+    // Count the digits first so groups can be aligned to the end of the number
+    let mut count = CountDigits(0);
+    bits.write_hex(&mut count)?;
+
+    bits.write_hex(HexDigits {
+        writer,
+        digits: count.0,
+        remaining: count.0,
+        options,
+    })
+}
+
+struct CountDigits(usize);
+
+impl Write for CountDigits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+struct HexDigits<W> {
+    writer: W,
+    digits: usize,
+    remaining: usize,
+    options: FmtOptions,
+}
+
+impl<W: Write> Write for HexDigits<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for digit in s.chars() {
+            if self.options.group_hex && self.remaining != self.digits && self.remaining % 4 == 0 {
+                self.writer.write_char('_')?;
+            }
+
+            if self.options.uppercase_hex {
+                self.writer.write_char(digit.to_ascii_uppercase())?;
+            } else {
+                self.writer.write_char(digit)?;
+            }
+
+            self.remaining = self.remaining.saturating_sub(1);
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "serde")]
 pub(crate) struct AsDisplay<'a, B>(pub(crate) &'a B);
 
@@ -97,29 +299,220 @@ This function will fail on any names that don't correspond to defined flags.
 Unknown bits will be retained.
 */
 pub fn from_str<B: Flags>(input: &str) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    from_str_with(input, "|")
+}
+
+/**
+Parse a flags value from text, using a custom separator between flags.
+
+Any whitespace around the separator will be ignored.
+
+This function will fail on any names that don't correspond to defined flags,
+or if `separator` is empty.
+Unknown bits will be retained.
+*/
+pub fn from_str_with<B: Flags>(input: &str, separator: &str) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    from_str_with_names(input, separator, B::from_name, invalid_named_flag)
+}
+
+/**
+Parse a flags value from text, where named flags may be qualified by the name of their flags type.
+
+Each named flag can be bare, like `A`, or qualified by `type_name`, like `Flags::A`, so text copied
+from the `Debug` output of other types can be parsed. This function will fail on any names that
+don't correspond to defined flags, or that are qualified by a different type name. If `type_name`
+is empty then only bare names are accepted.
+Unknown bits will be retained.
+*/
+pub fn from_str_qualified<B: Flags>(input: &str, type_name: &str) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    from_str_with_names(
+        input,
+        "|",
+        |flag| B::from_name(strip_type_prefix(flag, type_name).unwrap_or(flag)),
+        |flag, span| {
+            // A flag that's qualified by some other path is for a different type
+            if strip_type_prefix(flag, type_name).is_none() && flag.contains("::") {
+                Err(ParseError::mismatched_type_prefix(flag).with_span(span))
+            } else {
+                invalid_named_flag(flag, span)
+            }
+        },
+    )
+}
+
+// Strip a `type_name::` prefix from a named flag
+fn strip_type_prefix<'a>(flag: &'a str, type_name: &str) -> Option<&'a str> {
+    if type_name.is_empty() {
+        return None;
+    }
+
+    flag.strip_prefix(type_name)?.strip_prefix("::")
+}
+
+/**
+Parse a flags value from text, using the given options.
+
+This function will fail on any names that don't correspond to defined flags.
+Unknown bits will be retained.
+*/
+pub fn from_str_with_options<B: Flags>(input: &str, options: ParseOptions) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    // The empty token is only recognized on its own, not alongside other flags
+    if let Some(token) = options.empty_token {
+        if input.trim() == token {
+            return Ok(B::empty());
+        }
+    }
+
+    from_str(input)
+}
+
+/**
+Options for parsing a flags value from text with [`from_str_with_options`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    empty_token: Option<&'static str>,
+}
+
+impl ParseOptions {
+    /// Get the default options, which parse flags values the same way as [`from_str`].
+    pub const fn new() -> Self {
+        ParseOptions { empty_token: None }
+    }
+
+    /// Parse `token`, like `<empty>`, as an empty flags value.
+    ///
+    /// An empty string is still parsed as an empty flags value too.
+    pub const fn empty_token(self, token: &'static str) -> Self {
+        ParseOptions {
+            empty_token: Some(token),
+        }
+    }
+}
+
+/**
+Parse a flags value from text, skipping any names that don't correspond to defined flags.
+
+The names of any skipped flags are returned along with the parsed flags value.
+This function will still fail on invalid hex, binary, or octal numbers.
+Unknown bits will be retained.
+*/
+#[cfg(feature = "std")]
+pub fn from_str_lossy<B: Flags>(input: &str) -> Result<(B, Vec<String>), ParseError>
+where
+    B::Bits: ParseHex,
+{
+    let mut skipped = Vec::new();
+
+    let parsed = from_str_with_names(input, "|", B::from_name, |flag, _| {
+        skipped.push(flag.to_owned());
+        Ok(())
+    })?;
+
+    Ok((parsed, skipped))
+}
+
+/**
+Parse a flags value from text, skipping any names that don't correspond to defined flags.
+
+The number of skipped flags is returned along with the parsed flags value.
+This function will still fail on invalid hex, binary, or octal numbers.
+Unknown bits will be retained.
+*/
+pub fn from_str_lossy_count<B: Flags>(input: &str) -> Result<(B, usize), ParseError>
+where
+    B::Bits: ParseHex,
+{
+    let mut skipped = 0;
+
+    let parsed = from_str_with_names(input, "|", B::from_name, |_, _| {
+        skipped += 1;
+        Ok(())
+    })?;
+
+    Ok((parsed, skipped))
+}
+
+// Fail on a name that doesn't correspond to any defined flag
+fn invalid_named_flag(flag: &str, span: Range<usize>) -> Result<(), ParseError> {
+    Err(ParseError::invalid_named_flag(flag).with_span(span))
+}
+
+// Parse a flags value from text, using the given function to look up named flags
+//
+// Names that aren't found are passed to `unknown_name`, which may skip them or fail
+fn from_str_with_names<B: Flags>(
+    input: &str,
+    separator: &str,
+    from_name: impl Fn(&str) -> Option<B>,
+    mut unknown_name: impl FnMut(&str, Range<usize>) -> Result<(), ParseError>,
+) -> Result<B, ParseError>
 where
     B::Bits: ParseHex,
 {
     let mut parsed_flags = B::empty();
 
+    // An empty separator would match between every character
+    if separator.is_empty() {
+        return Err(ParseError::empty_separator());
+    }
+
     // If the input is empty then return an empty set of flags
     if input.trim().is_empty() {
         return Ok(parsed_flags);
     }
 
-    for flag in input.split('|') {
-        let flag = flag.trim();
-
+    for (flag, span) in split_flags(input, separator) {
         // If the flag is empty then we've got missing input
         if flag.is_empty() {
-            return Err(ParseError::empty_flag());
+            return Err(ParseError::empty_flag().with_span(span));
         }
 
+        // If the flag starts with `!` then it's removed from the flags parsed so far
+        let (flag, span, negated) = match flag.strip_prefix('!') {
+            Some(negated) => {
+                let negated = negated.trim_start();
+
+                if negated.is_empty() {
+                    return Err(ParseError::empty_negated_flag().with_span(span));
+                }
+
+                (negated, span.end - negated.len()..span.end, true)
+            }
+            None => (flag, span, false),
+        };
+
         // If the flag starts with `0x` then it's a hex number
         // Parse it directly to the underlying bits type
         let parsed_flag = if let Some(flag) = flag.strip_prefix("0x") {
-            let bits =
-                <B::Bits>::parse_hex(flag).map_err(|_| ParseError::invalid_hex_flag(flag))?;
+            let bits = <B::Bits>::parse_hex(flag)
+                .map_err(|_| ParseError::invalid_hex_flag(flag).with_span(span))?;
+
+            B::from_bits_retain(bits)
+        }
+        // If the flag starts with `0b` then it's a binary number
+        else if let Some(flag) = flag.strip_prefix("0b") {
+            let bits = <B::Bits>::parse_bin(flag)
+                .map_err(|_| ParseError::invalid_bin_flag(flag).with_span(span))?;
+
+            B::from_bits_retain(bits)
+        }
+        // If the flag starts with `0o` then it's an octal number
+        else if let Some(flag) = flag.strip_prefix("0o") {
+            let bits = <B::Bits>::parse_oct(flag)
+                .map_err(|_| ParseError::invalid_oct_flag(flag).with_span(span))?;
 
             B::from_bits_retain(bits)
         }
@@ -127,15 +520,42 @@ where
         // The generated flags type will determine whether
         // or not it's a valid identifier
         else {
-            B::from_name(flag).ok_or_else(|| ParseError::invalid_named_flag(flag))?
+            match from_name(flag) {
+                Some(flag) => flag,
+                None => {
+                    unknown_name(flag, span)?;
+                    continue;
+                }
+            }
         };
 
-        parsed_flags.insert(parsed_flag);
+        if negated {
+            parsed_flags.remove(parsed_flag);
+        } else {
+            parsed_flags.insert(parsed_flag);
+        }
     }
 
     Ok(parsed_flags)
 }
 
+// Split the input into separated flags, along with the span of each
+// trimmed flag in the original input
+fn split_flags<'a>(
+    input: &'a str,
+    separator: &'a str,
+) -> impl Iterator<Item = (&'a str, Range<usize>)> + 'a {
+    let mut offset = 0;
+
+    input.split(separator).map(move |flag| {
+        let start = offset + (flag.len() - flag.trim_start().len());
+        offset += flag.len() + separator.len();
+
+        let flag = flag.trim();
+        (flag, start..start + flag.len())
+    })
+}
+
 /**
 Write a flags value as text, ignoring any unknown bits.
 */
@@ -146,6 +566,48 @@ where
     to_writer(&B::from_bits_truncate(flags.bits()), writer)
 }
 
+/**
+Write a flags value as text, naming every contained flag.
+
+This function is like [`to_writer`], except it uses [`Flags::matching_flags`] instead of
+[`Flags::iter_names`], so every named flag whose bits are all set is written, even if its bits
+are already covered by other flags. Any bits that aren't part of a contained flag will be
+formatted as a hex number.
+
+The output can be parsed by [`from_str`] back into the same flags value, but it isn't the
+canonical text for it. Formatting the parsed value with [`to_writer`] won't reproduce the same
+text if any flags overlap.
+*/
+pub fn to_writer_verbose<B: Flags>(flags: &B, mut writer: impl Write) -> Result<(), fmt::Error>
+where
+    B::Bits: WriteHex,
+{
+    let mut first = true;
+    let mut remaining = B::from_bits_retain(flags.bits());
+    for (name, flag) in flags.matching_flags() {
+        if !first {
+            writer.write_str(" | ")?;
+        }
+
+        first = false;
+        writer.write_str(name)?;
+
+        remaining.remove(flag);
+    }
+
+    let remaining = remaining.bits();
+    if remaining != B::Bits::EMPTY {
+        if !first {
+            writer.write_str(" | ")?;
+        }
+
+        writer.write_str("0x")?;
+        remaining.write_hex(writer)?;
+    }
+
+    fmt::Result::Ok(())
+}
+
 /**
 Parse a flags value from text.
 
@@ -159,6 +621,30 @@ where
     Ok(B::from_bits_truncate(from_str::<B>(input)?.bits()))
 }
 
+/**
+Parse a flags value from text, ignoring the ASCII case of names.
+
+This function will fail on any names that don't correspond to defined flags.
+If multiple flags have names that only differ by case, the first one defined will be used.
+Unknown bits will be retained.
+*/
+pub fn from_str_case_insensitive<B: Flags>(input: &str) -> Result<B, ParseError>
+where
+    B::Bits: ParseHex,
+{
+    from_str_with_names(
+        input,
+        "|",
+        |name| {
+            B::FLAGS
+                .iter()
+                .find(|flag| flag.is_named() && flag.name().eq_ignore_ascii_case(name))
+                .map(|flag| B::from_bits_retain(flag.value().bits()))
+        },
+        invalid_named_flag,
+    )
+}
+
 /**
 Write only the contained, defined, named flags in a flags value as text.
 */
@@ -180,6 +666,26 @@ pub fn to_writer_strict<B: Flags>(flags: &B, mut writer: impl Write) -> Result<(
     fmt::Result::Ok(())
 }
 
+/**
+Write only the contained, defined, named flags in a flags value as text.
+
+This function will fail without writing anything if any bits don't correspond to a
+contained, defined, named flag.
+*/
+pub fn try_to_writer_strict<B: Flags>(flags: &B, writer: impl Write) -> Result<(), WriteError> {
+    // Check for any bits that `to_writer_strict` would ignore before writing anything
+    let mut iter = flags.iter_names();
+    for _ in &mut iter {}
+
+    if !iter.remaining().is_empty() {
+        return Err(WriteError::unnamed_bits());
+    }
+
+    to_writer_strict(flags, writer)?;
+
+    Ok(())
+}
+
 /**
 Parse a flags value from text.
 
@@ -197,21 +703,20 @@ pub fn from_str_strict<B: Flags>(input: &str) -> Result<B, ParseError> {
         return Ok(parsed_flags);
     }
 
-    for flag in input.split('|') {
-        let flag = flag.trim();
-
+    for (flag, span) in split_flags(input, "|") {
         // If the flag is empty then we've got missing input
         if flag.is_empty() {
-            return Err(ParseError::empty_flag());
+            return Err(ParseError::empty_flag().with_span(span));
         }
 
         // If the flag starts with `0x` then it's a hex number
         // These aren't supported in the strict parser
         if flag.starts_with("0x") {
-            return Err(ParseError::invalid_hex_flag("unsupported hex flag value"));
+            return Err(ParseError::invalid_hex_flag("unsupported hex flag value").with_span(span));
         }
 
-        let parsed_flag = B::from_name(flag).ok_or_else(|| ParseError::invalid_named_flag(flag))?;
+        let parsed_flag = B::from_name(flag)
+            .ok_or_else(|| ParseError::invalid_named_flag(flag).with_span(span))?;
 
         parsed_flags.insert(parsed_flag);
     }
@@ -222,7 +727,13 @@ pub fn from_str_strict<B: Flags>(input: &str) -> Result<B, ParseError> {
 /**
 Encode a value as a hex string.
 
-Implementors of this trait should not write the `0x` prefix.
+This is the dual of [`ParseHex`], and is used to write any bits that don't correspond to a
+contained flag, so custom [`Bits`] types need to implement it to be formatted as text.
+
+Implementors of this trait should not write the `0x` prefix, only the digits after it. Digits
+should be lowercase, without leading zeros or `_` separators, so the output can be parsed
+back by `ParseHex`. Options like [`FmtOptions::group_hex`] are applied by the caller, which may
+call `write_hex` more than once for the same value.
 */
 pub trait WriteHex {
     /// Write the value as hex.
@@ -231,97 +742,341 @@ pub trait WriteHex {
 
 /**
 Parse a value from a hex string.
+
+Implementors of this trait won't receive the `0x` prefix, only the digits after it. If the
+digits aren't a valid value then implementors should return [`ParseError::invalid_hex_flag`]
+with the input. The implementations for integers and byte arrays skip any `_` digit separators,
+but fail if the input has no digits.
+
+Custom [`Bits`] types need to implement this trait to be parsed from text. Binary and octal
+numbers are parsed by the `parse_bin` and `parse_oct` methods, which fail by default:
+
+```
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+use bitflags::{
+    parser::{self, ParseError, ParseHex},
+    Bits, Flag,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Word(u16);
+
+impl Bits for Word {
+    const EMPTY: Self = Word(0);
+    const ALL: Self = Word(u16::MAX);
+}
+
+impl ParseHex for Word {
+    fn parse_hex(input: &str) -> Result<Self, ParseError> {
+        u16::from_str_radix(input, 16)
+            .map(Word)
+            .map_err(|_| ParseError::invalid_hex_flag(input))
+    }
+
+    fn parse_bin(input: &str) -> Result<Self, ParseError> {
+        u16::from_str_radix(input, 2)
+            .map(Word)
+            .map_err(|_| ParseError::invalid_bin_flag(input))
+    }
+
+    fn parse_oct(input: &str) -> Result<Self, ParseError> {
+        u16::from_str_radix(input, 8)
+            .map(Word)
+            .map_err(|_| ParseError::invalid_oct_flag(input))
+    }
+}
+
+# impl BitAnd for Word { type Output = Self; fn bitand(self, other: Self) -> Self { Word(self.0 & other.0) } }
+# impl BitOr for Word { type Output = Self; fn bitor(self, other: Self) -> Self { Word(self.0 | other.0) } }
+# impl BitXor for Word { type Output = Self; fn bitxor(self, other: Self) -> Self { Word(self.0 ^ other.0) } }
+# impl Not for Word { type Output = Self; fn not(self) -> Self { Word(!self.0) } }
+#[derive(Debug, PartialEq, Eq)]
+struct WordFlags(Word);
+
+impl WordFlags {
+    const A: Self = WordFlags(Word(1));
+}
+
+impl bitflags::Flags for WordFlags {
+    const FLAGS: &'static [Flag<Self>] = &[Flag::new("A", WordFlags::A)];
+
+    type Bits = Word;
+
+    fn bits(&self) -> Word {
+        self.0
+    }
+
+    fn from_bits_retain(bits: Word) -> Self {
+        WordFlags(bits)
+    }
+}
+
+assert_eq!(WordFlags(Word(0xf1)), parser::from_str::<WordFlags>("A | 0xf0").unwrap());
+```
 */
 pub trait ParseHex {
     /// Parse the value from hex.
     fn parse_hex(input: &str) -> Result<Self, ParseError>
     where
         Self: Sized;
+
+    /// Parse the value from binary.
+    ///
+    /// Implementors won't receive the `0b` prefix, only the digits after it.
+    /// The default implementation fails with [`ParseError::invalid_bin_flag`].
+    fn parse_bin(input: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized,
+    {
+        Err(ParseError::invalid_bin_flag(input))
+    }
+
+    /// Parse the value from octal.
+    ///
+    /// Implementors won't receive the `0o` prefix, only the digits after it.
+    /// The default implementation fails with [`ParseError::invalid_oct_flag`].
+    fn parse_oct(input: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized,
+    {
+        Err(ParseError::invalid_oct_flag(input))
+    }
 }
 
 /// An error encountered while parsing flags from text.
 #[derive(Debug)]
-pub struct ParseError(ParseErrorKind);
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Option<Range<usize>>,
+    got: Option<Got>,
+}
 
-#[derive(Debug)]
+// The offending token is kept in a `String` when `std` is available,
+// and in a fixed-size inline buffer when it isn't
+#[cfg(feature = "std")]
+type Got = String;
+
+#[cfg(not(feature = "std"))]
+type Got = GotBuf;
+
+// The maximum number of bytes of an offending token kept without `std`
+#[cfg(not(feature = "std"))]
+const GOT_CAPACITY: usize = 32;
+
+#[cfg(not(feature = "std"))]
+struct GotBuf {
+    bytes: [u8; GOT_CAPACITY],
+    len: usize,
+    truncated: bool,
+}
+
+#[cfg(not(feature = "std"))]
+impl GotBuf {
+    fn new(token: impl fmt::Display) -> Self {
+        let mut got = GotBuf {
+            bytes: [0; GOT_CAPACITY],
+            len: 0,
+            truncated: false,
+        };
+
+        // Writing never fails; any overflowing text is dropped instead
+        let _ = write!(got, "{}", token);
+
+        got
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl core::ops::Deref for GotBuf {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        // Only whole chars are written, so the buffer is always valid UTF-8
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for GotBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let len = c.len_utf8();
+
+            if self.truncated || self.len + len > GOT_CAPACITY {
+                self.truncated = true;
+                return Ok(());
+            }
+
+            c.encode_utf8(&mut self.bytes[self.len..self.len + len]);
+            self.len += len;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl fmt::Debug for GotBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// The kind of error encountered while parsing flags from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 #[allow(clippy::enum_variant_names)]
-enum ParseErrorKind {
+pub enum ParseErrorKind {
+    /// A hex or named flag wasn't found between separators.
     EmptyFlag,
-    InvalidNamedFlag {
-        #[cfg(not(feature = "std"))]
-        got: (),
-        #[cfg(feature = "std")]
-        got: String,
-    },
-    InvalidHexFlag {
-        #[cfg(not(feature = "std"))]
-        got: (),
-        #[cfg(feature = "std")]
-        got: String,
-    },
+    /// A named flag that doesn't correspond to any on the flags type was encountered.
+    InvalidNamedFlag,
+    /// An invalid hex flag was encountered.
+    InvalidHexFlag,
+    /// An invalid binary flag was encountered.
+    InvalidBinFlag,
+    /// An invalid octal flag was encountered.
+    InvalidOctFlag,
+    /// An empty separator was given.
+    EmptySeparator,
+    /// A hex or named flag wasn't found after a `!`.
+    EmptyNegatedFlag,
+    /// A named flag was qualified by the name of a different flags type.
+    MismatchedTypePrefix,
 }
 
 impl ParseError {
     /// An invalid hex flag was encountered.
     pub fn invalid_hex_flag(flag: impl fmt::Display) -> Self {
-        let _flag = flag;
+        ParseError::with_got(ParseErrorKind::InvalidHexFlag, flag)
+    }
 
-        let got = {
-            #[cfg(feature = "std")]
-            {
-                _flag.to_string()
-            }
-        };
+    /// An invalid binary flag was encountered.
+    pub fn invalid_bin_flag(flag: impl fmt::Display) -> Self {
+        ParseError::with_got(ParseErrorKind::InvalidBinFlag, flag)
+    }
 
-        ParseError(ParseErrorKind::InvalidHexFlag { got })
+    /// An invalid octal flag was encountered.
+    pub fn invalid_oct_flag(flag: impl fmt::Display) -> Self {
+        ParseError::with_got(ParseErrorKind::InvalidOctFlag, flag)
     }
 
     /// A named flag that doesn't correspond to any on the flags type was encountered.
     pub fn invalid_named_flag(flag: impl fmt::Display) -> Self {
-        let _flag = flag;
-
-        let got = {
-            #[cfg(feature = "std")]
-            {
-                _flag.to_string()
-            }
-        };
+        ParseError::with_got(ParseErrorKind::InvalidNamedFlag, flag)
+    }
 
-        ParseError(ParseErrorKind::InvalidNamedFlag { got })
+    /// A named flag was qualified by the name of a different flags type.
+    pub fn mismatched_type_prefix(flag: impl fmt::Display) -> Self {
+        ParseError::with_got(ParseErrorKind::MismatchedTypePrefix, flag)
     }
 
     /// A hex or named flag wasn't found between separators.
     pub const fn empty_flag() -> Self {
-        ParseError(ParseErrorKind::EmptyFlag)
+        ParseError {
+            kind: ParseErrorKind::EmptyFlag,
+            span: None,
+            got: None,
+        }
+    }
+
+    /// An empty separator was given.
+    pub const fn empty_separator() -> Self {
+        ParseError {
+            kind: ParseErrorKind::EmptySeparator,
+            span: None,
+            got: None,
+        }
+    }
+
+    /// A hex or named flag wasn't found after a `!`.
+    pub const fn empty_negated_flag() -> Self {
+        ParseError {
+            kind: ParseErrorKind::EmptyNegatedFlag,
+            span: None,
+            got: None,
+        }
+    }
+
+    fn with_got(kind: ParseErrorKind, flag: impl fmt::Display) -> Self {
+        ParseError {
+            kind,
+            span: None,
+            #[cfg(feature = "std")]
+            got: Some(flag.to_string()),
+            #[cfg(not(feature = "std"))]
+            got: Some(GotBuf::new(flag)),
+        }
+    }
+
+    pub(crate) fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Get the kind of error that was encountered.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Get the token that caused the error.
+    ///
+    /// This method will return `None` for errors that don't have an offending token,
+    /// like [`ParseErrorKind::EmptyFlag`]. When the `std` feature isn't enabled, only the
+    /// first 32 bytes of the token are kept.
+    pub fn got(&self) -> Option<&str> {
+        self.got.as_deref()
+    }
+
+    /// Get the byte range of the flag that caused the error in the original input.
+    ///
+    /// The range excludes any whitespace around the flag. For an empty flag, the range will
+    /// be empty and point to where the flag was expected. This method will return `None`
+    /// if the error wasn't produced by a parser function.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
     }
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.0 {
-            ParseErrorKind::InvalidNamedFlag { got } => {
-                let _got = got;
-
+        match self.kind {
+            ParseErrorKind::InvalidNamedFlag => {
                 write!(f, "unrecognized named flag")?;
-
-                #[cfg(feature = "std")]
-                {
-                    write!(f, " `{}`", _got)?;
-                }
             }
-            ParseErrorKind::InvalidHexFlag { got } => {
-                let _got = got;
-
+            ParseErrorKind::InvalidHexFlag => {
                 write!(f, "invalid hex flag")?;
-
-                #[cfg(feature = "std")]
-                {
-                    write!(f, " `{}`", _got)?;
-                }
+            }
+            ParseErrorKind::InvalidBinFlag => {
+                write!(f, "invalid binary flag")?;
+            }
+            ParseErrorKind::InvalidOctFlag => {
+                write!(f, "invalid octal flag")?;
             }
             ParseErrorKind::EmptyFlag => {
                 write!(f, "encountered empty flag")?;
             }
+            ParseErrorKind::EmptySeparator => {
+                write!(f, "the separator between flags can't be empty")?;
+            }
+            ParseErrorKind::EmptyNegatedFlag => {
+                write!(f, "encountered `!` without a flag")?;
+            }
+            ParseErrorKind::MismatchedTypePrefix => {
+                write!(f, "named flag is qualified by a different type")?;
+            }
+        }
+
+        if let Some(got) = self.got() {
+            write!(f, " `{}", got)?;
+
+            #[cfg(not(feature = "std"))]
+            if self.got.as_ref().map_or(false, |got| got.truncated) {
+                f.write_str("…")?;
+            }
+
+            f.write_str("`")?;
         }
 
         Ok(())
@@ -330,3 +1085,55 @@ impl fmt::Display for ParseError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
+
+/// An error encountered while writing flags as text.
+#[derive(Debug)]
+pub struct WriteError {
+    kind: WriteErrorKind,
+}
+
+/// The kind of error encountered while writing flags as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WriteErrorKind {
+    /// The underlying writer failed.
+    Fmt,
+    /// Some bits didn't correspond to a contained, defined, named flag.
+    UnnamedBits,
+}
+
+impl WriteError {
+    /// Some bits didn't correspond to a contained, defined, named flag.
+    pub const fn unnamed_bits() -> Self {
+        WriteError {
+            kind: WriteErrorKind::UnnamedBits,
+        }
+    }
+
+    /// Get the kind of error that was encountered.
+    pub fn kind(&self) -> &WriteErrorKind {
+        &self.kind
+    }
+}
+
+impl From<fmt::Error> for WriteError {
+    fn from(_: fmt::Error) -> Self {
+        WriteError {
+            kind: WriteErrorKind::Fmt,
+        }
+    }
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            WriteErrorKind::Fmt => write!(f, "an error occurred when formatting flags"),
+            WriteErrorKind::UnnamedBits => {
+                write!(f, "encountered bits that don't correspond to a named flag")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WriteError {}
@@ -26,10 +26,12 @@ macro_rules! __declare_public_bitflags {
 #[doc(hidden)]
 macro_rules! __impl_public_bitflags_forward {
     (
+        inline: $inline:meta,
         $(#[$outer:meta])*
         $PublicBitFlags:ident: $T:ty, $InternalBitFlags:ident
     ) => {
         $crate::__impl_bitflags! {
+            inline: $inline,
             $(#[$outer])*
             $PublicBitFlags: $T {
                 fn empty() {
@@ -40,10 +42,22 @@ macro_rules! __impl_public_bitflags_forward {
                     Self($InternalBitFlags::all())
                 }
 
+                fn covers_all_bits() {
+                    $InternalBitFlags::covers_all_bits()
+                }
+
+                fn max_bit() {
+                    $InternalBitFlags::max_bit()
+                }
+
                 fn bits(f) {
                     f.0.bits()
                 }
 
+                fn into_bits(f) {
+                    f.0.into_bits()
+                }
+
                 fn from_bits(bits) {
                     match $InternalBitFlags::from_bits(bits) {
                         $crate::__private::core::option::Option::Some(bits) => $crate::__private::core::option::Option::Some(Self(bits)),
@@ -59,6 +73,12 @@ macro_rules! __impl_public_bitflags_forward {
                     Self($InternalBitFlags::from_bits_retain(bits))
                 }
 
+                fn from_bits_with_unknown(bits) {
+                    let (flags, unknown) = $InternalBitFlags::from_bits_with_unknown(bits);
+
+                    (Self(flags), unknown)
+                }
+
                 fn from_name(name) {
                     match $InternalBitFlags::from_name(name) {
                         $crate::__private::core::option::Option::Some(bits) => $crate::__private::core::option::Option::Some(Self(bits)),
@@ -82,6 +102,22 @@ macro_rules! __impl_public_bitflags_forward {
                     f.0.contains(other.0)
                 }
 
+                fn is_subset(f, other) {
+                    f.0.is_subset(other.0)
+                }
+
+                fn is_superset(f, other) {
+                    f.0.is_superset(other.0)
+                }
+
+                fn is_disjoint(f, other) {
+                    f.0.is_disjoint(other.0)
+                }
+
+                fn truncate(f) {
+                    f.0.truncate()
+                }
+
                 fn insert(f, other) {
                     f.0.insert(other.0)
                 }
@@ -94,6 +130,10 @@ macro_rules! __impl_public_bitflags_forward {
                     f.0.toggle(other.0)
                 }
 
+                fn toggle_all(f) {
+                    f.0.toggle_all()
+                }
+
                 fn set(f, other, value) {
                     f.0.set(other.0, value)
                 }
@@ -117,6 +157,45 @@ macro_rules! __impl_public_bitflags_forward {
                 fn complement(f) {
                     Self(f.0.complement())
                 }
+
+                fn complement_retain(f) {
+                    Self(f.0.complement_retain())
+                }
+
+                fn with(f, other) {
+                    Self(f.0.with(other.0))
+                }
+
+                fn without(f, other) {
+                    Self(f.0.without(other.0))
+                }
+
+                fn with_set(f, other, value) {
+                    Self(f.0.with_set(other.0, value))
+                }
+
+                fn cmp_by_bits(f, other) {
+                    f.0.cmp_by_bits(&other.0)
+                }
+
+                fn cmp_by_count(f, other) {
+                    f.0.cmp_by_count(&other.0)
+                }
+            }
+        }
+
+        // Only generated in `struct` mode, because `impl` mode flags types may be `repr(packed)`
+        #[allow(dead_code, deprecated, unused_attributes)]
+        $(#[$outer])*
+        impl $PublicBitFlags {
+            /// Get a mutable reference to the underlying bits value.
+            ///
+            /// Any bits may be written through the returned reference, including unknown bits
+            /// that don't correspond to a defined flag. This is the same as replacing the flags
+            /// value with [`from_bits_retain`](#method.from_bits_retain).
+            #[inline]
+            pub fn bits_mut(&mut self) -> &mut $T {
+                self.0.bits_mut()
             }
         }
     };
@@ -130,6 +209,7 @@ macro_rules! __impl_public_bitflags_forward {
 #[doc(hidden)]
 macro_rules! __impl_public_bitflags {
     (
+        inline: $inline:meta,
         $(#[$outer:meta])*
         $BitFlags:ident: $T:ty, $PublicBitFlags:ident {
             $(
@@ -139,6 +219,7 @@ macro_rules! __impl_public_bitflags {
         }
     ) => {
         $crate::__impl_bitflags! {
+            inline: $inline,
             $(#[$outer])*
             $BitFlags: $T {
                 fn empty() {
@@ -165,10 +246,39 @@ macro_rules! __impl_public_bitflags {
                     Self::from_bits_retain(truncated)
                 }
 
+                fn covers_all_bits() {
+                    Self::all().bits() == <$T as $crate::Bits>::ALL
+                }
+
+                fn max_bit() {
+                    let flags = <$PublicBitFlags as $crate::Flags>::FLAGS;
+
+                    let mut named = <$T as $crate::Bits>::EMPTY;
+                    let mut i = 0;
+
+                    while i < flags.len() {
+                        if flags[i].is_named() {
+                            named = named | flags[i].value().bits();
+                        }
+
+                        i += 1;
+                    }
+
+                    if named == <$T as $crate::Bits>::EMPTY {
+                        $crate::__private::core::option::Option::None
+                    } else {
+                        $crate::__private::core::option::Option::Some(<$T>::BITS - 1 - named.leading_zeros())
+                    }
+                }
+
                 fn bits(f) {
                     f.0
                 }
 
+                fn into_bits(f) {
+                    f.0
+                }
+
                 fn from_bits(bits) {
                     let truncated = Self::from_bits_truncate(bits).0;
 
@@ -187,6 +297,10 @@ macro_rules! __impl_public_bitflags {
                     Self(bits)
                 }
 
+                fn from_bits_with_unknown(bits) {
+                    (Self(bits), bits & !Self::all().bits())
+                }
+
                 fn from_name(name) {
                     $(
                         $crate::__bitflags_flag!({
@@ -195,7 +309,7 @@ macro_rules! __impl_public_bitflags {
                                 $crate::__bitflags_expr_safe_attrs!(
                                     $(#[$inner $($args)*])*
                                     {
-                                        if name == $crate::__private::core::stringify!($Flag) {
+                                        if $crate::__private::str_eq(name, $crate::__private::core::stringify!($Flag)) {
                                             return $crate::__private::core::option::Option::Some(Self($PublicBitFlags::$Flag.bits()));
                                         }
                                     }
@@ -227,6 +341,22 @@ macro_rules! __impl_public_bitflags {
                     f.bits() & other.bits() == other.bits()
                 }
 
+                fn is_subset(f, other) {
+                    f.bits() & other.bits() == f.bits()
+                }
+
+                fn is_superset(f, other) {
+                    f.contains(other)
+                }
+
+                fn is_disjoint(f, other) {
+                    !f.intersects(other)
+                }
+
+                fn truncate(f) {
+                    *f = Self::from_bits_truncate(f.bits());
+                }
+
                 fn insert(f, other) {
                     *f = Self::from_bits_retain(f.bits()).union(other);
                 }
@@ -239,6 +369,10 @@ macro_rules! __impl_public_bitflags {
                     *f = Self::from_bits_retain(f.bits()).symmetric_difference(other);
                 }
 
+                fn toggle_all(f) {
+                    *f = Self::from_bits_retain(f.bits()).complement();
+                }
+
                 fn set(f, other, value) {
                     if value {
                         f.insert(other);
@@ -266,6 +400,35 @@ macro_rules! __impl_public_bitflags {
                 fn complement(f) {
                     Self::from_bits_truncate(!f.bits())
                 }
+
+                fn complement_retain(f) {
+                    Self::from_bits_retain(!f.bits())
+                }
+
+                fn with(f, other) {
+                    f.union(other)
+                }
+
+                fn without(f, other) {
+                    f.difference(other)
+                }
+
+                fn with_set(f, other, value) {
+                    if value {
+                        f.union(other)
+                    } else {
+                        f.difference(other)
+                    }
+                }
+
+                fn cmp_by_bits(f, other) {
+                    $crate::__private::core::cmp::Ord::cmp(&f.bits(), &other.bits())
+                }
+
+                fn cmp_by_count(f, other) {
+                    $crate::__private::core::cmp::Ord::cmp(&f.bits().count_ones(), &other.bits().count_ones())
+                        .then_with(|| f.cmp_by_bits(other))
+                }
             }
         }
     };
@@ -276,6 +439,7 @@ macro_rules! __impl_public_bitflags {
 #[doc(hidden)]
 macro_rules! __impl_public_bitflags_iter {
     (
+        inline: $inline:meta,
         $(#[$outer:meta])*
         $BitFlags:ident: $T:ty, $PublicBitFlags:ident
     ) => {
@@ -285,12 +449,13 @@ macro_rules! __impl_public_bitflags_iter {
             ///
             /// Each yielded flags value will correspond to a defined named flag. Any unknown bits
             /// will be yielded together as a final flags value.
-            #[inline]
+            #[$inline]
             pub const fn iter(&self) -> $crate::iter::Iter<$PublicBitFlags> {
                 $crate::iter::Iter::__private_const_new(
                     <$PublicBitFlags as $crate::Flags>::FLAGS,
                     $PublicBitFlags::from_bits_retain(self.bits()),
                     $PublicBitFlags::from_bits_retain(self.bits()),
+                    $PublicBitFlags::from_bits_retain(self.bits()),
                 )
             }
 
@@ -304,6 +469,29 @@ macro_rules! __impl_public_bitflags_iter {
                     <$PublicBitFlags as $crate::Flags>::FLAGS,
                     $PublicBitFlags::from_bits_retain(self.bits()),
                     $PublicBitFlags::from_bits_retain(self.bits()),
+                    $PublicBitFlags::from_bits_retain(self.bits()),
+                )
+            }
+
+            /// Yield the position of each set bit.
+            ///
+            /// This method is unlike [`iter`](#method.iter), because it yields individual bit indexes
+            /// in ascending order instead of flags values, regardless of how bits correspond to flags.
+            #[inline]
+            pub const fn iter_bits(&self) -> $crate::iter::IterBits<$PublicBitFlags> {
+                $crate::iter::IterBits::__private_const_new(
+                    $PublicBitFlags::from_bits_retain(self.bits()),
+                )
+            }
+
+            /// Yield the names of all defined named flags.
+            ///
+            /// Names are yielded in the order their flags are defined, which is the same set
+            /// of names recognized by [`from_name`](#method.from_name).
+            #[inline]
+            pub const fn names() -> $crate::iter::Names<$PublicBitFlags> {
+                $crate::iter::Names::__private_const_new(
+                    <$PublicBitFlags as $crate::Flags>::FLAGS,
                 )
             }
         }
@@ -317,6 +505,26 @@ macro_rules! __impl_public_bitflags_iter {
                 self.iter()
             }
         }
+
+        $(#[$outer])*
+        impl<'a> $crate::__private::core::iter::IntoIterator for &'a $BitFlags {
+            type Item = $PublicBitFlags;
+            type IntoIter = $crate::iter::Iter<$PublicBitFlags>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        $(#[$outer])*
+        impl<'a> $crate::__private::core::iter::IntoIterator for &'a mut $BitFlags {
+            type Item = $PublicBitFlags;
+            type IntoIter = $crate::iter::Iter<$PublicBitFlags>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
     };
 }
 
@@ -496,6 +704,33 @@ macro_rules! __impl_public_bitflags_ops {
                 result
             }
         }
+
+        $(#[$outer])*
+        impl<'a> $crate::__private::core::iter::Extend<&'a $PublicBitFlags> for $PublicBitFlags {
+            /// The bitwise or (`|`) of the bits in each borrowed flags value.
+            fn extend<T: $crate::__private::core::iter::IntoIterator<Item = &'a Self>>(
+                &mut self,
+                iterator: T,
+            ) {
+                for item in iterator {
+                    self.insert(Self::from_bits_retain(item.bits()))
+                }
+            }
+        }
+
+        $(#[$outer])*
+        impl<'a> $crate::__private::core::iter::FromIterator<&'a $PublicBitFlags> for $PublicBitFlags {
+            /// The bitwise or (`|`) of the bits in each borrowed flags value.
+            fn from_iter<T: $crate::__private::core::iter::IntoIterator<Item = &'a Self>>(
+                iterator: T,
+            ) -> Self {
+                use $crate::__private::core::iter::Extend;
+
+                let mut result = Self::empty();
+                result.extend(iterator);
+                result
+            }
+        }
     };
 }
 
@@ -573,6 +808,45 @@ macro_rules! __impl_public_bitflags_consts {
             fn from_bits_retain(bits: $T) -> $PublicBitFlags {
                 $PublicBitFlags::from_bits_retain(bits)
             }
+
+            // The fallback after the `match` is unreachable unless some flags are unnamed or `cfg`'d
+            #[allow(unreachable_code)]
+            fn from_name(name: &str) -> $crate::__private::core::option::Option<$PublicBitFlags> {
+                // Match on names first so the compiler can generate an efficient lookup
+                // The inherent `from_name` is a `const fn`, so it can't match on strings
+                #[allow(unreachable_patterns)]
+                match name {
+                    $(
+                        $crate::__bitflags_flag!({
+                            name: $Flag,
+                            named: { $crate::__private::core::stringify!($Flag) },
+                            unnamed: { "" },
+                        }) => {
+                            $crate::__bitflags_flag!({
+                                name: $Flag,
+                                named: {
+                                    $crate::__bitflags_expr_safe_attrs!(
+                                        $(#[$inner $($args)*])*
+                                        {
+                                            #[allow(
+                                                deprecated,
+                                                non_upper_case_globals,
+                                            )]
+                                            return $crate::__private::core::option::Option::Some($PublicBitFlags::$Flag)
+                                        }
+                                    );
+                                },
+                                unnamed: {},
+                            });
+                        }
+                    )*
+                    _ => return $crate::__private::core::option::Option::None,
+                }
+
+                // The name matched a flag that was removed by `cfg`, or is empty
+                // Check every flag in case another one with the same name exists
+                $PublicBitFlags::from_name(name)
+            }
         }
     };
 }
@@ -1,30 +1,68 @@
+mod added_removed;
 mod all;
+mod all_named;
 mod bitflags_match;
 mod bits;
+mod bits_count;
+mod bits_mut;
+mod bool_array;
+mod canonical;
+mod cfg;
+mod clear;
+mod cmp_by_bits;
+mod cmp_by_count;
 mod complement;
+mod complement_retain;
 mod contains;
+mod count;
+mod covers_all_bits;
 mod difference;
 mod empty;
 mod eq;
+mod exactly_one;
 mod extend;
 mod flags;
 mod fmt;
 mod from_bits;
 mod from_bits_retain;
 mod from_bits_truncate;
+mod from_bits_with_unknown;
 mod from_name;
+mod from_names;
+mod highest_set_flag;
 mod insert;
+mod insert_truncate;
 mod intersection;
+mod intersection_all;
 mod intersects;
+mod into_bits;
 mod is_all;
+mod is_disjoint;
 mod is_empty;
+mod is_subset;
+mod is_superset;
 mod iter;
+mod lowest_set_flag;
+mod map_bits;
+mod max_bit;
+mod named_bits;
+mod names;
 mod parser;
 mod remove;
+mod remove_truncate;
+mod retain;
+mod set_ops;
+mod signed;
 mod symmetric_difference;
+mod toggle_all;
 mod truncate;
+mod try_from_bits;
+mod try_insert;
 mod union;
+mod union_all;
 mod unknown;
+mod with;
+mod without;
 
 bitflags! {
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -131,4 +169,23 @@ bitflags! {
         /// External
         const _ = !0;
     }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    pub struct TestSigned: i8 {
+        /// 1
+        const A = 1;
+
+        /// 1 << 7
+        const SIGN = 1 << 7;
+    }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+    #[bitflags(non_exhaustive)]
+    pub struct TestNonExhaustive: u8 {
+        /// 1
+        const A = 1;
+
+        /// 1 << 1
+        const B = 1 << 1;
+    }
 }
@@ -0,0 +1,75 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), TestFlags::empty(), 0, 0);
+    case(TestFlags::empty(), TestFlags::all(), 1 | 1 << 1 | 1 << 2, 0);
+    case(TestFlags::all(), TestFlags::empty(), 0, 1 | 1 << 1 | 1 << 2);
+    case(
+        TestFlags::A | TestFlags::B,
+        TestFlags::B | TestFlags::C,
+        1 << 2,
+        1,
+    );
+    case(TestFlags::A, TestFlags::A, 0, 0);
+
+    case(
+        TestFlags::A,
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+        1 << 3,
+        0,
+    );
+
+    case(TestOverlapping::AB, TestOverlapping::BC, 1 << 2, 1);
+    case(
+        TestOverlapping::AB | TestOverlapping::BC,
+        TestOverlapping::BC,
+        0,
+        1,
+    );
+
+    case(TestZero::ZERO, TestZero::ZERO, 0, 0);
+}
+
+#[test]
+fn names() {
+    let (added, removed) = (TestFlags::A | TestFlags::B).added_removed(TestFlags::B | TestFlags::C);
+
+    assert_eq!(
+        vec!["C"],
+        added.iter_names().map(|(name, _)| name).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["A"],
+        removed
+            .iter_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(old: T, new: T, added: T::Bits, removed: T::Bits)
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    let (actual_added, actual_removed) =
+        T::from_bits_retain(old.bits()).added_removed(T::from_bits_retain(new.bits()));
+
+    assert_eq!(
+        (added, removed),
+        (actual_added.bits(), actual_removed.bits()),
+        "{:?}.added_removed({:?})",
+        old,
+        new
+    );
+
+    // Swapping the arguments swaps the halves
+    let (swapped_added, swapped_removed) = new.added_removed(old);
+    assert_eq!(
+        (removed, added),
+        (swapped_added.bits(), swapped_removed.bits()),
+    );
+}
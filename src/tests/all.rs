@@ -11,6 +11,8 @@ fn cases() {
     case(0, TestEmpty::all);
 
     case(!0, TestExternal::all);
+
+    case(!0, TestNonExhaustive::all);
 }
 
 #[track_caller]
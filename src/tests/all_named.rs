@@ -0,0 +1,26 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(1 | 1 << 1 | 1 << 2, TestFlags::all_named);
+
+    case(0, TestZero::all_named);
+
+    case(0, TestEmpty::all_named);
+
+    case(1 | 1 << 1 | 1 << 2, TestExternal::all_named);
+
+    case(0, TestExternalFull::all_named);
+
+    case(1 | 1 << 1, TestNonExhaustive::all_named);
+}
+
+#[track_caller]
+fn case<T: Flags>(expected: T::Bits, f: impl FnOnce() -> T)
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(expected, f().bits(), "Flags::all_named()");
+}
@@ -0,0 +1,28 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(0, TestFlags::empty());
+    case(1, TestFlags::A);
+    case(3, TestFlags::A | TestFlags::B | TestFlags::C);
+    case(3, TestFlags::ABC | TestFlags::A);
+    case(4, TestFlags::all() | TestFlags::from_bits_retain(1 << 3));
+    case(1, TestFlags::from_bits_retain(1 << 7));
+
+    case(0, TestZero::ZERO);
+
+    case(3, TestOverlapping::AB | TestOverlapping::BC);
+    case(1, TestOverlappingFull::A | TestOverlappingFull::B);
+
+    case(8, TestExternal::all());
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(expected: u32, value: T)
+where
+    T::Bits: crate::iter::BitPositions,
+{
+    assert_eq!(expected, value.bits_count(), "{:?}.bits_count()", value);
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn cases() {
+    let mut flags = TestFlags::A;
+
+    *flags.bits_mut() |= 1 << 1;
+    assert_eq!(TestFlags::A | TestFlags::B, flags);
+
+    // Unknown bits can be written through the reference
+    *flags.bits_mut() = 1 << 3;
+    assert_eq!(1 << 3, flags.bits());
+    assert_eq!(TestFlags::from_bits_retain(1 << 3), flags);
+
+    let mut flags = TestExternal::empty();
+
+    write(flags.bits_mut(), 0xff);
+    assert_eq!(0xff, flags.bits());
+    assert!(flags.is_all());
+}
+
+// Like an FFI function that writes flags through a pointer
+fn write(bits: &mut u8, value: u8) {
+    *bits = value;
+}
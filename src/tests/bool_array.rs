@@ -0,0 +1,71 @@
+bitflags! {
+    #[bitflags(bool_array)]
+    #[derive(Debug, PartialEq, Eq)]
+    struct TestBoolArray: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+        const D = 1 << 3;
+    }
+}
+
+bitflags! {
+    #[bitflags(bool_array)]
+    #[derive(Debug, PartialEq, Eq)]
+    struct TestBoolArrayOverlapping: u8 {
+        const ZERO = 0;
+        const A = 1;
+        const _ = 1 << 1;
+        const AC = 1 | 1 << 2;
+    }
+}
+
+#[test]
+fn cases() {
+    assert_eq!(4, TestBoolArray::COUNT);
+
+    case([false; 4], TestBoolArray::empty());
+    case([true, false, false, false], TestBoolArray::A);
+    case(
+        [false, true, false, true],
+        TestBoolArray::B | TestBoolArray::D,
+    );
+    case([true; 4], TestBoolArray::all());
+
+    assert_eq!(
+        [true, false, false, false],
+        (TestBoolArray::A | TestBoolArray::from_bits_retain(1 << 5)).as_bools()
+    );
+    assert_eq!(
+        TestBoolArray::C,
+        TestBoolArray::from_bools([false, false, true, false])
+    );
+}
+
+#[test]
+fn overlapping() {
+    assert_eq!(3, TestBoolArrayOverlapping::COUNT);
+
+    assert_eq!(
+        [true, false, false],
+        TestBoolArrayOverlapping::empty().as_bools()
+    );
+    assert_eq!([true, true, false], TestBoolArrayOverlapping::A.as_bools());
+    assert_eq!([true, true, true], TestBoolArrayOverlapping::AC.as_bools());
+
+    assert_eq!(
+        TestBoolArrayOverlapping::AC,
+        TestBoolArrayOverlapping::from_bools([false, true, true])
+    );
+}
+
+#[track_caller]
+fn case(bools: [bool; TestBoolArray::COUNT], value: TestBoolArray) {
+    assert_eq!(bools, value.as_bools(), "{:?}.as_bools()", value);
+    assert_eq!(
+        value,
+        TestBoolArray::from_bools(bools),
+        "from_bools({:?})",
+        bools
+    );
+}
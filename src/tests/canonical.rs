@@ -0,0 +1,52 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(0, TestFlags::empty());
+    case(1, TestFlags::A);
+    case(1, TestFlags::A | TestFlags::from_bits_retain(1 << 3));
+    case(0, TestFlags::from_bits_retain(1 << 3 | 1 << 7));
+
+    case(0, TestZero::from_bits_retain(1));
+
+    case(
+        1 | 1 << 1,
+        TestOverlapping::AB | TestOverlapping::from_bits_retain(1 << 3),
+    );
+
+    case(
+        1 << 3 | 1 << 7,
+        TestExternal::from_bits_retain(1 << 3 | 1 << 7),
+    );
+}
+
+#[test]
+fn dedup() {
+    let a = TestFlags::A;
+    let b = TestFlags::A | TestFlags::from_bits_retain(1 << 3);
+
+    assert_ne!(a, b);
+    assert_eq!(a.canonical(), b.canonical());
+
+    let set = [a, b, TestFlags::B]
+        .iter()
+        .map(|flags| flags.canonical())
+        .collect::<std::collections::BTreeSet<_>>();
+
+    assert_eq!(2, set.len());
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(expected: T::Bits, value: T)
+where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        Flags::canonical(value).bits(),
+        "Flags::canonical({:?})",
+        value
+    );
+}
@@ -0,0 +1,75 @@
+use crate::{parser, Flags};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct TestCfg: u8 {
+        const A = 1;
+
+        // Never enabled
+        #[cfg(any())]
+        const B = 1 << 1;
+
+        // Only the enabled definition is used
+        #[cfg(any())]
+        const C = 1 << 2;
+        #[cfg(all())]
+        const C = 1 << 3;
+
+        #[cfg(any())]
+        const _ = 1 << 4;
+    }
+}
+
+#[test]
+fn flags() {
+    assert_eq!(
+        vec![("A", 1), ("C", 1 << 3)],
+        TestCfg::FLAGS
+            .iter()
+            .map(|flag| (flag.name(), flag.value().bits()))
+            .collect::<Vec<_>>(),
+    );
+
+    // Disabled flags don't contribute to known bits
+    assert_eq!(1 | 1 << 3, TestCfg::all().bits());
+}
+
+#[test]
+fn iter_names() {
+    let flags = TestCfg::from_bits_retain(0b11111);
+
+    assert_eq!(
+        vec![("A", TestCfg::A), ("C", TestCfg::C)],
+        flags.iter_names().collect::<Vec<_>>(),
+    );
+    assert_eq!(
+        vec![("C", TestCfg::C), ("A", TestCfg::A)],
+        flags.iter_names().rev().collect::<Vec<_>>(),
+    );
+
+    // Bits of disabled flags are left over
+    let mut iter = flags.iter_names();
+    assert_eq!(2, iter.by_ref().count());
+    assert_eq!(0b10110, iter.remaining().bits());
+}
+
+#[test]
+fn from_name() {
+    assert_eq!(Some(TestCfg::A), TestCfg::from_name("A"));
+    assert_eq!(
+        Some(1 << 3),
+        TestCfg::from_name("C").map(|flag| flag.bits())
+    );
+    assert_eq!(None, TestCfg::from_name("B"));
+    assert_eq!(None, TestCfg::from_name("_"));
+}
+
+#[test]
+fn parse() {
+    assert_eq!(TestCfg::C, parser::from_str::<TestCfg>("C").unwrap());
+    assert!(parser::from_str::<TestCfg>("A | B").is_err());
+
+    let mut formatted = String::new();
+    parser::to_writer(&TestCfg::from_bits_retain(0b1110), &mut formatted).unwrap();
+    assert_eq!("C | 0x6", formatted);
+}
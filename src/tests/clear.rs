@@ -0,0 +1,28 @@
+use super::*;
+
+use crate::{Bits, Flags};
+
+#[test]
+fn cases() {
+    case(TestFlags::empty());
+
+    case(TestFlags::A);
+    case(TestFlags::all());
+    case(TestFlags::from_bits_retain(1 << 3));
+
+    case(TestZero::ZERO);
+
+    case(TestExternal::from_bits_retain(0xff));
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(value: T)
+where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    let mut cleared = value;
+    cleared.clear();
+
+    assert!(cleared.is_empty(), "{:?}.clear()", value);
+    assert_eq!(T::Bits::EMPTY, cleared.bits(), "{:?}.clear()", value);
+}
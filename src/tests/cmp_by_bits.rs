@@ -0,0 +1,51 @@
+use super::*;
+
+use std::cmp::Ordering;
+
+#[test]
+fn cases() {
+    case(Ordering::Equal, TestFlags::empty(), TestFlags::empty());
+    case(Ordering::Less, TestFlags::empty(), TestFlags::A);
+    case(Ordering::Less, TestFlags::A | TestFlags::B, TestFlags::C);
+    case(
+        Ordering::Greater,
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::all(),
+    );
+}
+
+#[test]
+fn sort() {
+    let mut flags = vec![
+        TestFlags::C,
+        TestFlags::A | TestFlags::B,
+        TestFlags::empty(),
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::A,
+    ];
+
+    flags.sort_by(TestFlags::cmp_by_bits);
+
+    assert_eq!(
+        vec![
+            TestFlags::empty(),
+            TestFlags::A,
+            TestFlags::A | TestFlags::B,
+            TestFlags::C,
+            TestFlags::from_bits_retain(1 << 3),
+        ],
+        flags
+    );
+}
+
+#[track_caller]
+fn case(expected: Ordering, a: TestFlags, b: TestFlags) {
+    assert_eq!(expected, a.cmp_by_bits(&b), "{:?}.cmp_by_bits({:?})", a, b);
+    assert_eq!(
+        expected.reverse(),
+        b.cmp_by_bits(&a),
+        "{:?}.cmp_by_bits({:?})",
+        b,
+        a
+    );
+}
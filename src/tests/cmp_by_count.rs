@@ -0,0 +1,60 @@
+use super::*;
+
+use std::cmp::Ordering;
+
+#[test]
+fn cases() {
+    case(Ordering::Equal, TestFlags::empty(), TestFlags::empty());
+    case(Ordering::Less, TestFlags::empty(), TestFlags::A);
+    case(Ordering::Less, TestFlags::C, TestFlags::A | TestFlags::B);
+    case(Ordering::Less, TestFlags::A, TestFlags::C);
+    case(
+        Ordering::Less,
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::all(),
+    );
+}
+
+#[test]
+fn sort() {
+    let mut flags = vec![
+        TestFlags::all(),
+        TestFlags::A | TestFlags::B,
+        TestFlags::C,
+        TestFlags::empty(),
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::A,
+    ];
+
+    flags.sort_by(TestFlags::cmp_by_count);
+
+    assert_eq!(
+        vec![
+            TestFlags::empty(),
+            TestFlags::A,
+            TestFlags::C,
+            TestFlags::from_bits_retain(1 << 3),
+            TestFlags::A | TestFlags::B,
+            TestFlags::all(),
+        ],
+        flags
+    );
+}
+
+#[track_caller]
+fn case(expected: Ordering, a: TestFlags, b: TestFlags) {
+    assert_eq!(
+        expected,
+        a.cmp_by_count(&b),
+        "{:?}.cmp_by_count({:?})",
+        a,
+        b
+    );
+    assert_eq!(
+        expected.reverse(),
+        b.cmp_by_count(&a),
+        "{:?}.cmp_by_count({:?})",
+        b,
+        a
+    );
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        !(1 | 1 << 1 | 1 << 2),
+        TestFlags::all(),
+        TestFlags::complement_retain,
+    );
+    case(
+        0,
+        TestFlags::from_bits_retain(!0),
+        TestFlags::complement_retain,
+    );
+
+    case(!(1 << 2), TestFlags::C, TestFlags::complement_retain);
+    case(
+        !(1 << 2 | 1 << 3),
+        TestFlags::C | TestFlags::from_bits_retain(1 << 3),
+        TestFlags::complement_retain,
+    );
+
+    case(!0, TestFlags::empty(), TestFlags::complement_retain);
+
+    case(!0, TestZero::empty(), TestZero::complement_retain);
+
+    case(!0, TestEmpty::empty(), TestEmpty::complement_retain);
+
+    case(
+        1 << 2 | !0 << 3,
+        TestOverlapping::AB,
+        TestOverlapping::complement_retain,
+    );
+
+    case(!0, TestExternal::empty(), TestExternal::complement_retain);
+    case(
+        !(1 << 5),
+        TestExternal::from_bits_retain(1 << 5),
+        TestExternal::complement_retain,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    expected: T::Bits,
+    value: T,
+    inherent: impl FnOnce(T) -> T,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        inherent(value).bits(),
+        "{:?}.complement_retain()",
+        value
+    );
+}
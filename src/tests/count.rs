@@ -0,0 +1,54 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+#[cfg(not(miri))] // Very slow in miri
+fn roundtrip() {
+    for bits in 0u8..=255 {
+        assert_eq!(
+            TestFlags::from_bits_retain(bits).iter().count(),
+            Flags::count(&TestFlags::from_bits_retain(bits))
+        );
+        assert_eq!(
+            TestOverlapping::from_bits_retain(bits).iter().count(),
+            Flags::count(&TestOverlapping::from_bits_retain(bits))
+        );
+        assert_eq!(
+            TestExternal::from_bits_retain(bits).iter().count(),
+            Flags::count(&TestExternal::from_bits_retain(bits))
+        );
+    }
+}
+
+#[test]
+fn cases() {
+    case(0, TestFlags::empty());
+
+    case(1, TestFlags::A);
+    case(3, TestFlags::all());
+    case(1, TestFlags::from_bits_retain(1 << 3));
+    case(
+        2,
+        TestFlags::A | TestFlags::from_bits_retain(1 << 3 | 1 << 4),
+    );
+
+    case(1, TestFlagsInvert::all());
+
+    case(0, TestZero::ZERO);
+
+    case(1, TestOverlapping::AB);
+    case(2, TestOverlapping::AB | TestOverlapping::BC);
+    case(1, TestOverlapping::from_bits_retain(1 << 1));
+
+    case(1, TestOverlappingFull::A | TestOverlappingFull::B);
+    case(2, TestOverlappingFull::C | TestOverlappingFull::D);
+
+    case(4, TestExternal::all());
+    case(1, TestExternalFull::all());
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(expected: usize, value: T) {
+    assert_eq!(expected, Flags::count(&value), "Flags::count({:?})", value);
+}
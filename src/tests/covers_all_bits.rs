@@ -0,0 +1,26 @@
+use super::*;
+
+// Checked at compile time, like a fully packed flags type would
+const _: () = assert!(TestExternalFull::covers_all_bits());
+
+#[test]
+fn cases() {
+    assert!(!TestFlags::covers_all_bits());
+    assert!(!TestEmpty::covers_all_bits());
+    assert!(!TestZero::covers_all_bits());
+
+    assert!(TestExternal::covers_all_bits());
+    assert!(TestExternalFull::covers_all_bits());
+}
+
+bitflags! {
+    struct TestPacked: u8 {
+        const LOW = 0x0f;
+        const HIGH = 0xf0;
+    }
+}
+
+#[test]
+fn packed() {
+    assert!(TestPacked::covers_all_bits());
+}
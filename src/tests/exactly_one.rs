@@ -0,0 +1,51 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(None, TestFlags::empty());
+    case(Some(1), TestFlags::A);
+    case(Some(1 << 2), TestFlags::C);
+    case(None, TestFlags::A | TestFlags::B);
+    case(None, TestFlags::ABC);
+    case(Some(1 << 3), TestFlags::from_bits_retain(1 << 3));
+    case(None, TestFlags::A | TestFlags::from_bits_retain(1 << 3));
+
+    case(None, TestZero::ZERO);
+    case(Some(1), TestZeroOne::ONE);
+
+    // Multiple unknown bits are yielded together
+    case(
+        Some(1 << 3 | 1 << 4),
+        TestFlags::from_bits_retain(1 << 3 | 1 << 4),
+    );
+
+    // A bit of a multi-bit flag on its own is an unknown bit
+    case(Some(1 << 2), TestOverlapping::from_bits_retain(1 << 2));
+    case(Some(1 << 1), TestOverlappingFull::D);
+
+    case(Some(1 | 1 << 1), TestOverlapping::AB);
+    case(Some(1 << 1), TestOverlapping::from_bits_retain(1 << 1));
+    case(None, TestOverlapping::AB | TestOverlapping::BC);
+
+    case(Some(1), TestExternal::A);
+    case(Some(1 << 5), TestExternal::from_bits_retain(1 << 5));
+    case(
+        None,
+        TestExternal::A | TestExternal::from_bits_retain(1 << 5),
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(expected: Option<T::Bits>, value: T)
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq + crate::iter::BitPositions,
+{
+    assert_eq!(
+        expected,
+        Flags::exactly_one(&value).map(|f| f.bits()),
+        "Flags::exactly_one({:?})",
+        value
+    );
+}
@@ -17,6 +17,19 @@ fn cases() {
     assert_eq!(TestFlags::ABC | TestFlags::from_bits_retain(1 << 5), flags);
 }
 
+#[test]
+fn borrowed() {
+    let mut flags = TestFlags::empty();
+
+    flags.extend(&[TestFlags::A, TestFlags::B]);
+
+    assert_eq!(TestFlags::A | TestFlags::B, flags);
+
+    flags.extend([TestFlags::C, TestFlags::from_bits_retain(1 << 5)].iter());
+
+    assert_eq!(TestFlags::ABC | TestFlags::from_bits_retain(1 << 5), flags);
+}
+
 mod external {
     use super::*;
 
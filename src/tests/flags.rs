@@ -22,6 +22,36 @@ fn cases() {
     assert_eq!(0, TestEmpty::FLAGS.iter().count());
 }
 
+#[test]
+fn named() {
+    assert!(TestFlags::FLAGS.iter().all(|flag| flag.is_named()));
+    assert!(!TestFlags::FLAGS.iter().any(|flag| flag.is_unnamed()));
+
+    let unnamed = TestExternal::FLAGS
+        .iter()
+        .filter(|flag| flag.is_unnamed())
+        .map(|flag| (flag.name(), flag.value().bits()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![("", !0u8)], unnamed);
+}
+
+#[test]
+fn new() {
+    const NAMED: crate::Flag<TestFlags> = crate::Flag::new("A", TestFlags::A);
+    const UNNAMED: crate::Flag<TestFlags> = crate::Flag::new("", TestFlags::B);
+
+    assert_eq!("A", NAMED.name());
+    assert_eq!(TestFlags::A, *NAMED.value());
+    assert!(NAMED.is_named());
+    assert!(!NAMED.is_unnamed());
+
+    assert_eq!("", UNNAMED.name());
+    assert_eq!(TestFlags::B, *UNNAMED.value());
+    assert!(!UNNAMED.is_named());
+    assert!(UNNAMED.is_unnamed());
+}
+
 mod external {
     use super::*;
 
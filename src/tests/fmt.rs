@@ -76,6 +76,103 @@ fn cases() {
         "377",
         "11111111",
     );
+
+    case(
+        TestNonExhaustive::from_bits(0xff).unwrap(),
+        "TestNonExhaustive(A | B | 0xfc)",
+        "FF",
+        "ff",
+        "377",
+        "11111111",
+    );
+}
+
+#[test]
+fn derive_display() {
+    bitflags! {
+        #[bitflags(derive_display)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(non_exhaustive, derive_display)]
+        struct NonExhaustive: u8 {
+            const A = 1;
+        }
+    }
+
+    assert_eq!("", format!("{}", Flags::empty()));
+    assert_eq!("A | B", format!("{}", Flags::A | Flags::B));
+    assert_eq!(
+        "A | 0x8",
+        format!("{}", Flags::A | Flags::from_bits_retain(1 << 3))
+    );
+
+    assert_eq!("A | 0xfe", format!("{}", NonExhaustive::all()));
+    assert_eq!(
+        "NonExhaustive(A | 0xfe)",
+        format!("{:?}", NonExhaustive::all())
+    );
+}
+
+#[test]
+fn debug_names_and_bits() {
+    bitflags! {
+        #[derive(Debug)]
+        #[bitflags(debug = "names_and_bits")]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+
+        #[derive(Debug)]
+        #[bitflags(debug = "names")]
+        struct NamesOnly: u8 {
+            const A = 1;
+        }
+    }
+
+    assert_eq!("Flags(0x0)", format!("{:?}", Flags::empty()));
+    assert_eq!("Flags(A | B = 0x3)", format!("{:?}", Flags::A | Flags::B));
+    assert_eq!(
+        "Flags(A | 0x8 = 0x9)",
+        format!("{:?}", Flags::A | Flags::from_bits_retain(1 << 3))
+    );
+    assert_eq!(
+        "Flags(0x8 = 0x8)",
+        format!("{:?}", Flags::from_bits_retain(1 << 3))
+    );
+    assert_eq!(
+        "Flags(\n    A |\n    B = 0x3,\n)",
+        format!("{:#?}", Flags::A | Flags::B)
+    );
+
+    assert_eq!("NamesOnly(A)", format!("{:?}", NamesOnly::A));
+}
+
+#[test]
+fn alternate() {
+    assert_eq!(
+        "TestFlags(\n    0x0,\n)",
+        format!("{:#?}", TestFlags::empty())
+    );
+    assert_eq!("TestFlags(\n    A,\n)", format!("{:#?}", TestFlags::A));
+    assert_eq!(
+        "TestFlags(\n    A |\n    B |\n    C,\n)",
+        format!("{:#?}", TestFlags::all())
+    );
+    assert_eq!(
+        "TestFlags(\n    A |\n    0x8,\n)",
+        format!("{:#?}", TestFlags::A | TestFlags::from_bits_retain(1 << 3))
+    );
+    assert_eq!(
+        "TestFlags(\n    0x8,\n)",
+        format!("{:#?}", TestFlags::from_bits_retain(1 << 3))
+    );
 }
 
 #[track_caller]
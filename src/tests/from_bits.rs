@@ -20,6 +20,8 @@ fn cases() {
     case(Some(1 << 1), 1 << 1, TestOverlapping::from_bits);
 
     case(Some(1 << 5), 1 << 5, TestExternal::from_bits);
+
+    case(Some(0xff), 0xff, TestNonExhaustive::from_bits);
 }
 
 #[track_caller]
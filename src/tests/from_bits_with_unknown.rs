@@ -0,0 +1,44 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(0, 0, TestFlags::from_bits_with_unknown);
+    case(1, 0, TestFlags::from_bits_with_unknown);
+    case(1 | 1 << 1 | 1 << 2, 0, TestFlags::from_bits_with_unknown);
+
+    case(1 << 3, 1 << 3, TestFlags::from_bits_with_unknown);
+    case(1 | 1 << 3, 1 << 3, TestFlags::from_bits_with_unknown);
+    case(0xff, 0xf8, TestFlags::from_bits_with_unknown);
+
+    case(1 | 1 << 1, 0, TestOverlapping::from_bits_with_unknown);
+    case(1 << 1, 0, TestOverlapping::from_bits_with_unknown);
+    case(1 << 3, 1 << 3, TestOverlapping::from_bits_with_unknown);
+
+    case(1, 1, TestZero::from_bits_with_unknown);
+
+    case(1 << 5, 0, TestExternal::from_bits_with_unknown);
+}
+
+#[track_caller]
+fn case<T: Flags>(input: T::Bits, unknown: T::Bits, inherent: impl FnOnce(T::Bits) -> (T, T::Bits))
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    let (flags, inherent_unknown) = inherent(input);
+    assert_eq!(
+        (input, unknown),
+        (flags.bits(), inherent_unknown),
+        "T::from_bits_with_unknown({:?})",
+        input
+    );
+
+    let (flags, trait_unknown) = T::from_bits_with_unknown(input);
+    assert_eq!(
+        (input, unknown),
+        (flags.bits(), trait_unknown),
+        "Flags::from_bits_with_unknown({:?})",
+        input
+    );
+}
@@ -22,6 +22,88 @@ fn cases() {
     case(None, "", TestExternal::from_name);
 }
 
+#[test]
+fn const_fn() {
+    const A: Option<TestFlags> = TestFlags::from_name("A");
+    const ABC: Option<TestFlags> = TestFlags::from_name("ABC");
+    const UNKNOWN: Option<TestFlags> = TestFlags::from_name("D");
+    const EXTERNAL: Option<TestExternal> = TestExternal::from_name("");
+
+    assert_eq!(Some(TestFlags::A), A);
+    assert_eq!(Some(TestFlags::ABC), ABC);
+    assert_eq!(None, UNKNOWN);
+    assert_eq!(None, EXTERNAL);
+}
+
+#[test]
+fn large() {
+    bitflags! {
+        struct Large: u32 {
+            const F0 = 1 << 0;
+            const F1 = 1 << 1;
+            const F2 = 1 << 2;
+            const F3 = 1 << 3;
+            const F4 = 1 << 4;
+            const F5 = 1 << 5;
+            const F6 = 1 << 6;
+            const F7 = 1 << 7;
+            const F8 = 1 << 8;
+            const F9 = 1 << 9;
+            const F10 = 1 << 10;
+            const F11 = 1 << 11;
+            const F12 = 1 << 12;
+            const F13 = 1 << 13;
+            const F14 = 1 << 14;
+            const F15 = 1 << 15;
+            const F16 = 1 << 16;
+            const F17 = 1 << 17;
+            const F18 = 1 << 18;
+            const F19 = 1 << 19;
+            const F20 = 1 << 20;
+            const F21 = 1 << 21;
+            const F22 = 1 << 22;
+            const F23 = 1 << 23;
+            const F24 = 1 << 24;
+            const F25 = 1 << 25;
+            const F26 = 1 << 26;
+            const F27 = 1 << 27;
+            const F28 = 1 << 28;
+            const F29 = 1 << 29;
+            const F30 = 1 << 30;
+            const F31 = 1 << 31;
+        }
+    }
+
+    for (i, flag) in Large::FLAGS.iter().enumerate() {
+        case(Some(1 << i), flag.name(), Large::from_name);
+    }
+
+    case(None, "F32", Large::from_name);
+    case(None, "f0", Large::from_name);
+}
+
+#[test]
+fn cfg() {
+    bitflags! {
+        struct Cfg: u8 {
+            #[cfg(any())]
+            const A = 1;
+            #[cfg(all())]
+            const A = 1 << 1;
+
+            #[cfg(any())]
+            const B = 1 << 2;
+
+            const _ = 1 << 3;
+        }
+    }
+
+    case(Some(1 << 1), "A", Cfg::from_name);
+    case(None, "B", Cfg::from_name);
+    case(None, "", Cfg::from_name);
+    case(None, "_", Cfg::from_name);
+}
+
 #[track_caller]
 fn case<T: Flags>(expected: Option<T::Bits>, input: &str, inherent: impl FnOnce(&str) -> Option<T>)
 where
@@ -0,0 +1,47 @@
+use super::*;
+
+use crate::{parser::ParseErrorKind, Flags};
+
+#[test]
+fn cases() {
+    case::<TestFlags>(Ok(0), &[]);
+
+    case::<TestFlags>(Ok(1), &["A"]);
+    case::<TestFlags>(Ok(1 | 1 << 2), &["A", "C"]);
+    case::<TestFlags>(Ok(1 | 1 << 1 | 1 << 2), &["A", "ABC"]);
+    case::<TestFlags>(Ok(1), &["A", "A"]);
+
+    case::<TestFlags>(Err(ParseErrorKind::InvalidNamedFlag), &["a"]);
+    case::<TestFlags>(Err(ParseErrorKind::InvalidNamedFlag), &["A", "D"]);
+    case::<TestFlags>(Err(ParseErrorKind::InvalidNamedFlag), &["0x1"]);
+    case::<TestFlags>(Err(ParseErrorKind::InvalidNamedFlag), &["A | B"]);
+    case::<TestFlags>(Err(ParseErrorKind::EmptyFlag), &["A", ""]);
+
+    case::<TestZero>(Ok(0), &["ZERO"]);
+
+    case::<TestUnicode>(Ok(1 | 1 << 1), &["一", "二"]);
+
+    case::<TestExternal>(Err(ParseErrorKind::InvalidNamedFlag), &["_"]);
+}
+
+#[test]
+fn invalid_got() {
+    let err = TestFlags::from_names(["A", "D", "E"]).unwrap_err();
+
+    assert_eq!(Some("D"), err.got());
+}
+
+#[track_caller]
+fn case<T: Flags>(expected: Result<T::Bits, ParseErrorKind>, input: &[&str])
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        T::from_names(input.iter().copied())
+            .map(|f| f.bits())
+            .map_err(|e| *e.kind()),
+        "Flags::from_names({:?})",
+        input
+    );
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(None, TestFlags::empty());
+    case(Some(1), TestFlags::A);
+    case(Some(1 << 1), TestFlags::A | TestFlags::B);
+    case(Some(1 | 1 << 1 | 1 << 2), TestFlags::all());
+    case(None, TestFlags::from_bits_retain(1 << 3));
+    case(Some(1), TestFlags::A | TestFlags::from_bits_retain(1 << 3));
+
+    case(None, TestZero::ZERO);
+    case(Some(1), TestZeroOne::ONE);
+
+    case(Some(1 | 1 << 1), TestOverlapping::AB);
+    case(
+        Some(1 << 1 | 1 << 2),
+        TestOverlapping::from_bits_retain(0b111),
+    );
+    case(None, TestOverlapping::from_bits_retain(1 << 1));
+
+    case(Some(1 << 1), TestOverlappingFull::all());
+
+    case(Some(1 | 1 << 1 | 1 << 2), TestExternal::all());
+    case(None, TestExternal::from_bits_retain(1 << 5));
+
+    // The sign bit is compared as the greatest bit
+    case(Some(1 << 7), TestSigned::SIGN);
+    case(Some(1 << 7), TestSigned::all());
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug>(expected: Option<T::Bits>, value: T)
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq + crate::iter::BitPositions,
+{
+    assert_eq!(
+        expected,
+        Flags::highest_set_flag(&value).map(|f| f.bits()),
+        "Flags::highest_set_flag({:?})",
+        value
+    );
+}
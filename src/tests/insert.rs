@@ -87,5 +87,26 @@ fn case<T: Flags + std::fmt::Debug + Copy>(
             value,
             input
         );
+
+        assert_eq!(
+            (*expected != value.bits(), *expected),
+            {
+                let mut value = value;
+                (Flags::insert_checked(&mut value, *input), value.bits())
+            },
+            "Flags::insert_checked({:?}, {:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            (*expected != value.bits(), *expected),
+            {
+                let mut value = value;
+                (Flags::set_checked(&mut value, *input, true), value.bits())
+            },
+            "Flags::set_checked({:?}, {:?}, true)",
+            value,
+            input
+        );
     }
 }
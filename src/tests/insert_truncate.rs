@@ -0,0 +1,67 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::A, 1),
+            (TestFlags::A | TestFlags::B, 1 | 1 << 1),
+            (TestFlags::empty(), 0),
+            (TestFlags::from_bits_retain(1 << 3), 0),
+            (TestFlags::B | TestFlags::from_bits_retain(1 << 3), 1 << 1),
+        ],
+    );
+
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 4),
+        &[
+            (TestFlags::A, 1 | 1 << 4),
+            (TestFlags::from_bits_retain(1 << 3), 1 | 1 << 4),
+            (
+                TestFlags::C | TestFlags::from_bits_retain(1 << 3),
+                1 | 1 << 2 | 1 << 4,
+            ),
+        ],
+    );
+
+    case(
+        TestExternal::empty(),
+        &[(TestExternal::from_bits_retain(1 << 5), 1 << 5)],
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(value: T, inputs: &[(T, T::Bits)])
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                value.insert_truncate(*input);
+                value
+            }
+            .bits(),
+            "{:?}.insert_truncate({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                value.set_truncate(*input, true);
+                value
+            }
+            .bits(),
+            "{:?}.set_truncate({:?}, true)",
+            value,
+            input
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(&[], 1 | 1 << 1 | 1 << 2);
+    case(&[TestFlags::A], 1);
+    case(&[TestFlags::A, TestFlags::C], 0);
+    case(&[TestFlags::A, TestFlags::A | TestFlags::B], 1);
+    case(
+        &[TestFlags::all(), TestFlags::B | TestFlags::C],
+        1 << 1 | 1 << 2,
+    );
+    case(&[TestFlags::from_bits_retain(1 << 3)], 1 << 3);
+    case(
+        &[
+            TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+            TestFlags::from_bits_retain(1 << 3),
+        ],
+        1 << 3,
+    );
+
+    assert_eq!(TestFlags::all(), TestFlags::intersection_all([]));
+    assert_eq!(TestEmpty::all(), TestEmpty::intersection_all([]));
+    assert_eq!(!0, TestExternal::intersection_all([]).bits());
+}
+
+#[track_caller]
+fn case(inputs: &[TestFlags], expected: u8) {
+    assert_eq!(
+        expected,
+        TestFlags::intersection_all(inputs.iter().copied()).bits(),
+        "TestFlags::intersection_all({:?})",
+        inputs
+    );
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(0, TestFlags::empty(), TestFlags::into_bits);
+
+    case(1, TestFlags::A, TestFlags::into_bits);
+    case(1 | 1 << 1 | 1 << 2, TestFlags::ABC, TestFlags::into_bits);
+
+    case(
+        !0,
+        TestFlags::from_bits_retain(u8::MAX),
+        TestFlags::into_bits,
+    );
+    case(
+        1 << 3,
+        TestFlags::from_bits_retain(1 << 3),
+        TestFlags::into_bits,
+    );
+
+    case(
+        1 << 3,
+        TestZero::from_bits_retain(1 << 3),
+        TestZero::into_bits,
+    );
+
+    case(
+        1 << 3,
+        TestEmpty::from_bits_retain(1 << 3),
+        TestEmpty::into_bits,
+    );
+
+    case(
+        1 << 4 | 1 << 6,
+        TestExternal::from_bits_retain(1 << 4 | 1 << 6),
+        TestExternal::into_bits,
+    );
+}
+
+#[test]
+fn chain() {
+    const BITS: u8 = TestFlags::A.union(TestFlags::C).into_bits();
+
+    assert_eq!(1 | 1 << 2, BITS);
+    assert_eq!(
+        1 << 1,
+        (TestFlags::A | TestFlags::B)
+            .difference(TestFlags::A)
+            .into_bits()
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    expected: T::Bits,
+    value: T,
+    inherent: impl FnOnce(T) -> T::Bits,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(expected, inherent(value), "{:?}.into_bits()", value);
+    assert_eq!(
+        expected,
+        Flags::into_bits(value),
+        "Flags::into_bits({:?})",
+        value
+    );
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), true),
+        ],
+        TestFlags::is_disjoint,
+    );
+
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, false),
+            (TestFlags::B, true),
+            (TestFlags::ABC, false),
+            (TestFlags::from_bits_retain(1 << 3), true),
+            (TestFlags::from_bits_retain(1 | 1 << 3), false),
+        ],
+        TestFlags::is_disjoint,
+    );
+
+    case(
+        TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::from_bits_retain(1 << 3), false),
+            (TestFlags::from_bits_retain(1 | 1 << 3), false),
+        ],
+        TestFlags::is_disjoint,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[
+            (TestOverlapping::AB, false),
+            (TestOverlapping::BC, false),
+            (TestOverlapping::from_bits_retain(1 << 1), false),
+        ],
+        TestOverlapping::is_disjoint,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, bool)],
+    mut inherent: impl FnMut(&T, T) -> bool,
+) {
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(&value, *input),
+            "{:?}.is_disjoint({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::is_disjoint(&value, *input),
+            "Flags::is_disjoint({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::empty(), true),
+            (TestFlags::A, true),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), true),
+        ],
+        TestFlags::is_subset,
+    );
+
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::empty(), false),
+            (TestFlags::A, true),
+            (TestFlags::B, false),
+            (TestFlags::ABC, true),
+            (TestFlags::from_bits_retain(1 << 3), false),
+            (TestFlags::from_bits_retain(1 | 1 << 3), true),
+        ],
+        TestFlags::is_subset,
+    );
+
+    case(
+        TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::empty(), false),
+            (TestFlags::A, false),
+            (TestFlags::from_bits_retain(1 << 3), true),
+            (TestFlags::from_bits_retain(1 | 1 << 3), true),
+        ],
+        TestFlags::is_subset,
+    );
+
+    case(
+        TestOverlapping::AB,
+        &[
+            (TestOverlapping::AB, true),
+            (TestOverlapping::BC, false),
+            (TestOverlapping::from_bits_retain(1 << 1), false),
+        ],
+        TestOverlapping::is_subset,
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, bool)],
+    mut inherent: impl FnMut(&T, T) -> bool,
+) {
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(&value, *input),
+            "{:?}.is_subset({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::is_subset(&value, *input),
+            "Flags::is_subset({:?}, {:?})",
+            value,
+            input
+        );
+    }
+}
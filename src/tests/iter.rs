@@ -22,12 +22,144 @@ fn roundtrip() {
     }
 }
 
+#[test]
+#[cfg(not(miri))] // Very slow in miri
+fn roundtrip_rev() {
+    fn case<T: Flags + std::fmt::Debug>(value: T)
+    where
+        T::Bits: std::fmt::Debug + Ord,
+    {
+        let mut forward = value.iter().map(|f| f.bits()).collect::<Vec<_>>();
+        forward.reverse();
+        assert_eq!(
+            forward,
+            value.iter().rev().map(|f| f.bits()).collect::<Vec<_>>(),
+            "{:?}.iter().rev()",
+            value
+        );
+
+        let mut forward = value
+            .iter_names()
+            .map(|(n, f)| (n, f.bits()))
+            .collect::<Vec<_>>();
+        forward.reverse();
+        assert_eq!(
+            forward,
+            value
+                .iter_names()
+                .rev()
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>(),
+            "{:?}.iter_names().rev()",
+            value
+        );
+
+        // Alternating between the front and back yields the same flags
+        let mut forward = value.iter().map(|f| f.bits()).collect::<Vec<_>>();
+        let mut iter = value.iter();
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        loop {
+            match iter.next() {
+                Some(f) => front.push(f.bits()),
+                None => break,
+            }
+            match iter.next_back() {
+                Some(f) => back.push(f.bits()),
+                None => break,
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        forward.sort();
+        front.sort();
+        assert_eq!(forward, front, "{:?}.iter() from both ends", value);
+    }
+
+    for bits in 0u8..=255 {
+        case(TestFlags::from_bits_retain(bits));
+        case(TestFlagsInvert::from_bits_retain(bits));
+        case(TestOverlapping::from_bits_retain(bits));
+        case(TestOverlappingFull::from_bits_retain(bits));
+        case(TestExternal::from_bits_retain(bits));
+        case(TestExternalFull::from_bits_retain(bits));
+    }
+}
+
+#[test]
+fn clone() {
+    let flags = TestFlags::A | TestFlags::C | TestFlags::from_bits_retain(1 << 3);
+
+    let mut iter = flags.iter();
+    assert_eq!(Some(TestFlags::A), iter.next());
+
+    let cloned = iter.clone();
+    assert_eq!(2, cloned.clone().count());
+    assert_eq!(iter.collect::<Vec<_>>(), cloned.collect::<Vec<_>>());
+
+    let mut iter = flags.iter_names();
+    assert_eq!(Some(("A", TestFlags::A)), iter.next());
+
+    let cloned = iter.clone();
+    assert_eq!(iter.remaining(), cloned.remaining());
+    assert_eq!(iter.collect::<Vec<_>>(), cloned.collect::<Vec<_>>());
+}
+
+#[test]
+#[cfg(not(miri))] // Very slow in miri
+fn clone_rev() {
+    fn case<T: Flags + std::fmt::Debug>(value: T)
+    where
+        T::Bits: std::fmt::Debug,
+    {
+        // Cloning after each step and finishing from the back yields the same names
+        let mut iter = value.iter_names();
+        loop {
+            let mut front = iter.clone();
+            let mut forward = front
+                .by_ref()
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>();
+            forward.reverse();
+
+            let mut back = iter.clone();
+            let backward = back
+                .by_ref()
+                .rev()
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(forward, backward, "{:?}.iter_names().rev()", value);
+            assert_eq!(
+                front.remaining().bits(),
+                back.remaining().bits(),
+                "{:?}.iter_names().remaining()",
+                value
+            );
+
+            if iter.next().is_none() {
+                break;
+            }
+        }
+    }
+
+    for bits in 0u8..=255 {
+        case(TestFlags::from_bits_retain(bits));
+        case(TestOverlapping::from_bits_retain(bits));
+        case(TestOverlappingFull::from_bits_retain(bits));
+        case(TestExternal::from_bits_retain(bits));
+    }
+}
+
 mod collect {
     use super::*;
 
     #[test]
     fn cases() {
-        assert_eq!(0, [].into_iter().collect::<TestFlags>().bits());
+        assert_eq!(
+            0,
+            [TestFlags::A; 0].into_iter().collect::<TestFlags>().bits()
+        );
 
         assert_eq!(1, [TestFlags::A,].into_iter().collect::<TestFlags>().bits());
 
@@ -63,6 +195,21 @@ mod collect {
             .bits()
         );
     }
+
+    #[test]
+    fn borrowed() {
+        assert_eq!(
+            1 | 1 << 2,
+            [&TestFlags::A, &TestFlags::C]
+                .into_iter()
+                .collect::<TestFlags>()
+                .bits()
+        );
+
+        let flags = [TestFlags::B, TestFlags::from_bits_retain(1 << 3)];
+
+        assert_eq!(1 << 1 | 1 << 3, flags.iter().collect::<TestFlags>().bits());
+    }
 }
 
 mod iter {
@@ -128,6 +275,55 @@ mod iter {
             "{:?}.into_iter()",
             value
         );
+
+        let mut expected = expected.to_vec();
+        expected.reverse();
+        assert_eq!(
+            expected,
+            Flags::iter(&value)
+                .rev()
+                .map(|f| f.bits())
+                .collect::<Vec<_>>(),
+            "Flags::iter({:?}).rev()",
+            value
+        );
+    }
+
+    #[test]
+    fn borrowed() {
+        let mut flags = TestFlags::A | TestFlags::C | TestFlags::from_bits_retain(1 << 3);
+
+        let mut iterated = Vec::new();
+        for flag in &flags {
+            iterated.push(flag);
+        }
+
+        assert_eq!(flags.iter().collect::<Vec<_>>(), iterated);
+
+        let mut iterated = Vec::new();
+        for flag in &mut flags {
+            iterated.push(flag);
+        }
+
+        assert_eq!(flags.iter().collect::<Vec<_>>(), iterated);
+    }
+
+    #[test]
+    fn remaining() {
+        let flags = TestFlags::A | TestFlags::C | TestFlags::from_bits_retain(1 << 3 | 1 << 5);
+
+        let mut iter = flags.iter();
+        assert_eq!(flags.bits(), iter.remaining().bits());
+
+        assert_eq!(Some(TestFlags::A), iter.next());
+        assert_eq!(1 << 2 | 1 << 3 | 1 << 5, iter.remaining().bits());
+
+        for _ in &mut iter {}
+        assert_eq!(1 << 3 | 1 << 5, iter.remaining().bits());
+
+        let mut iter = TestFlags::ABC.iter();
+        for _ in &mut iter {}
+        assert!(iter.remaining().is_empty());
     }
 }
 
@@ -205,5 +401,208 @@ mod iter_names {
             "Flags::iter_names({:?})",
             value
         );
+
+        let mut expected = expected.to_vec();
+        expected.reverse();
+        assert_eq!(
+            expected,
+            Flags::iter_names(&value)
+                .rev()
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>(),
+            "Flags::iter_names({:?}).rev()",
+            value
+        );
+    }
+}
+
+mod matching_flags {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        case(&[], TestFlags::empty());
+
+        case(&[("A", 1)], TestFlags::A);
+        case(
+            &[("A", 1), ("B", 1 << 1)],
+            TestFlags::A | TestFlags::B | TestFlags::from_bits_retain(1 << 3),
+        );
+
+        case(
+            &[
+                ("A", 1),
+                ("B", 1 << 1),
+                ("C", 1 << 2),
+                ("ABC", 1 | 1 << 1 | 1 << 2),
+            ],
+            TestFlags::ABC,
+        );
+
+        case(&[("AB", 1 | 1 << 1)], TestOverlapping::AB);
+        case(
+            &[("AB", 1 | 1 << 1), ("BC", 1 << 1 | 1 << 2)],
+            TestOverlapping::AB | TestOverlapping::BC,
+        );
+        case(&[], TestOverlapping::from_bits_retain(1 << 1));
+
+        case(&[("A", 1), ("B", 1), ("C", 1)], TestOverlappingFull::A);
+
+        case(&[], TestZero::ZERO);
+    }
+
+    #[test]
+    fn iter_names() {
+        let flags = TestOverlapping::AB | TestOverlapping::BC;
+
+        assert_eq!(2, flags.matching_flags().count());
+        assert_eq!(2, flags.iter_names().count());
+
+        let flags = TestOverlappingFull::A;
+
+        assert_eq!(3, flags.matching_flags().count());
+        assert_eq!(1, flags.iter_names().count());
+    }
+
+    #[track_caller]
+    fn case<T: Flags + std::fmt::Debug>(expected: &[(&'static str, T::Bits)], value: T)
+    where
+        T::Bits: std::fmt::Debug + PartialEq,
+    {
+        assert_eq!(
+            expected,
+            Flags::matching_flags(&value)
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>(),
+            "Flags::matching_flags({:?})",
+            value
+        );
+
+        let mut expected = expected.to_vec();
+        expected.reverse();
+        assert_eq!(
+            expected,
+            Flags::matching_flags(&value)
+                .rev()
+                .map(|(n, f)| (n, f.bits()))
+                .collect::<Vec<_>>(),
+            "Flags::matching_flags({:?}).rev()",
+            value
+        );
+    }
+}
+
+mod iter_bits {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        case(&[], TestFlags::empty(), TestFlags::iter_bits);
+
+        case(&[0], TestFlags::A, TestFlags::iter_bits);
+        case(&[0, 1, 2], TestFlags::ABC, TestFlags::iter_bits);
+        case(
+            &[0, 3, 7],
+            TestFlags::A | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+            TestFlags::iter_bits,
+        );
+
+        case(&[], TestZero::ZERO, TestZero::iter_bits);
+
+        case(&[0, 1], TestOverlapping::AB, TestOverlapping::iter_bits);
+        case(
+            &[0, 1, 2],
+            TestOverlapping::AB | TestOverlapping::BC,
+            TestOverlapping::iter_bits,
+        );
+
+        case(
+            &[0, 1, 2, 3, 4, 5, 6, 7],
+            TestExternal::all(),
+            TestExternal::iter_bits,
+        );
+    }
+
+    #[test]
+    fn signed() {
+        bitflags! {
+            struct Signed: i8 {
+                const A = 1;
+                const B = i8::MIN;
+            }
+        }
+
+        assert_eq!(
+            vec![0, 7],
+            (Signed::A | Signed::B).iter_bits().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![7], Signed::B.iter_bits().collect::<Vec<_>>());
+    }
+
+    #[track_caller]
+    fn case<T: Flags + std::fmt::Debug>(
+        expected: &[u32],
+        value: T,
+        inherent: impl FnOnce(&T) -> crate::iter::IterBits<T>,
+    ) where
+        T::Bits: crate::iter::BitPositions,
+    {
+        assert_eq!(
+            expected,
+            inherent(&value).collect::<Vec<_>>(),
+            "{:?}.iter_bits()",
+            value
+        );
+        assert_eq!(
+            expected,
+            Flags::iter_bits(&value).collect::<Vec<_>>(),
+            "Flags::iter_bits({:?})",
+            value
+        );
+    }
+}
+
+mod iter_unknown {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        case(&[], TestFlags::empty());
+
+        case(&[], TestFlags::ABC);
+        case(
+            &[3, 7],
+            TestFlags::A | TestFlags::from_bits_retain(1 << 3 | 1 << 7),
+        );
+        case(
+            &[4, 5, 6],
+            TestFlags::from_bits_retain(1 << 1 | 1 << 4 | 1 << 5 | 1 << 6),
+        );
+
+        case(&[0, 7], TestZero::from_bits_retain(1 | 1 << 7));
+
+        case(
+            &[3],
+            TestOverlapping::AB | TestOverlapping::from_bits_retain(1 << 3),
+        );
+        case(
+            &[3, 7],
+            TestOverlapping::from_bits_retain(1 << 2 | 1 << 3 | 1 << 7),
+        );
+
+        case(&[], TestExternal::from_bits_retain(1 << 3 | 1 << 7));
+    }
+
+    #[track_caller]
+    fn case<T: Flags + std::fmt::Debug>(expected: &[u32], value: T)
+    where
+        T::Bits: crate::iter::BitPositions,
+    {
+        assert_eq!(
+            expected,
+            Flags::iter_unknown(&value).collect::<Vec<_>>(),
+            "Flags::iter_unknown({:?})",
+            value
+        );
     }
 }
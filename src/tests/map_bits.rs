@@ -0,0 +1,42 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(0, TestFlags::empty(), |bits| bits);
+    case(1 << 1, TestFlags::A, |bits| bits << 1);
+    case(1 << 3, TestFlags::C, |bits| bits << 1);
+    case(1, TestFlags::B, |bits| bits >> 1);
+    case(!0, TestFlags::empty(), |bits| !bits);
+
+    case(1 << 5, TestExternal::from_bits_retain(1 << 4), |bits| {
+        bits << 1
+    });
+}
+
+#[test]
+fn roundtrip() {
+    let flags = TestFlags::A | TestFlags::B;
+
+    assert_eq!(
+        flags,
+        flags.map_bits(|bits| bits << 4).map_bits(|bits| bits >> 4)
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    expected: T::Bits,
+    value: T,
+    f: impl Fn(T::Bits) -> T::Bits,
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(
+        expected,
+        Flags::map_bits(value, f).bits(),
+        "Flags::map_bits({:?})",
+        value
+    );
+}
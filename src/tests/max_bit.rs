@@ -0,0 +1,41 @@
+use super::*;
+
+// Checked at compile time, like a fixed-width field in a binary protocol would
+const _: () = assert!(matches!(TestFlags::max_bit(), Some(bit) if bit < 3));
+
+const MAX_BIT: Option<u32> = TestFlags::max_bit();
+
+#[test]
+fn cases() {
+    assert_eq!(Some(2), MAX_BIT);
+
+    assert_eq!(Some(2), TestFlags::max_bit());
+    assert_eq!(Some(2), TestFlagsInvert::max_bit());
+    assert_eq!(Some(2), TestOverlapping::max_bit());
+    assert_eq!(Some(1), TestOverlappingFull::max_bit());
+
+    assert_eq!(None, TestEmpty::max_bit());
+    assert_eq!(None, TestZero::max_bit());
+
+    // Unnamed flags aren't considered
+    assert_eq!(Some(2), TestExternal::max_bit());
+    assert_eq!(None, TestExternalFull::max_bit());
+}
+
+bitflags! {
+    struct TestMaxBitSigned: i8 {
+        const A = 1;
+        const SIGN = i8::MIN;
+    }
+
+    struct TestMaxBitWide: u128 {
+        const LOW = 1;
+        const HIGH = 1 << 100;
+    }
+}
+
+#[test]
+fn wide() {
+    assert_eq!(Some(7), TestMaxBitSigned::max_bit());
+    assert_eq!(Some(100), TestMaxBitWide::max_bit());
+}
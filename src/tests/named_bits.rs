@@ -0,0 +1,35 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case::<TestFlags>(1 | 1 << 1 | 1 << 2, TestFlags::named_bits);
+
+    case::<TestZero>(0, TestZero::named_bits);
+
+    case::<TestEmpty>(0, TestEmpty::named_bits);
+
+    case::<TestExternal>(1 | 1 << 1 | 1 << 2, TestExternal::named_bits);
+
+    case::<TestExternalFull>(0, TestExternalFull::named_bits);
+
+    case::<TestNonExhaustive>(1 | 1 << 1, TestNonExhaustive::named_bits);
+}
+
+#[test]
+fn mask() {
+    let bits = 1 | 1 << 3 | 1 << 7;
+
+    assert_eq!(1, bits & TestExternal::named_bits());
+    assert_eq!(bits, bits & TestExternal::all().bits());
+}
+
+#[track_caller]
+fn case<T: Flags>(expected: T::Bits, f: impl FnOnce() -> T::Bits)
+where
+    <T as Flags>::Bits: std::fmt::Debug + PartialEq,
+{
+    assert_eq!(expected, f(), "Flags::named_bits()");
+    assert_eq!(expected, T::all_named().bits(), "Flags::all_named()");
+}
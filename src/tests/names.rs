@@ -0,0 +1,40 @@
+use super::*;
+
+use crate::{iter::Names, Flags};
+
+#[test]
+fn cases() {
+    case(&["A", "B", "C", "ABC"], TestFlags::names);
+    case(&["ABC", "A", "B", "C"], TestFlagsInvert::names);
+
+    case(&["ZERO"], TestZero::names);
+    case(&["ZERO", "ONE"], TestZeroOne::names);
+
+    case(&["一", "二"], TestUnicode::names);
+
+    case(&[], TestEmpty::names);
+
+    case(&["AB", "BC"], TestOverlapping::names);
+    case(&["A", "B", "C", "D"], TestOverlappingFull::names);
+
+    case(&["A", "B", "C", "ABC"], TestExternal::names);
+    case(&[], TestExternalFull::names);
+}
+
+#[track_caller]
+fn case<T: Flags>(expected: &[&'static str], inherent: impl FnOnce() -> Names<T>) {
+    assert_eq!(expected, inherent().collect::<Vec<_>>(), "T::names()");
+    assert_eq!(expected, T::names().collect::<Vec<_>>(), "Flags::names()");
+
+    let mut expected = expected.to_vec();
+    expected.reverse();
+    assert_eq!(
+        expected,
+        T::names().rev().collect::<Vec<_>>(),
+        "Flags::names().rev()"
+    );
+
+    for name in T::names() {
+        assert!(T::from_name(name).is_some(), "Flags::from_name({:?})", name);
+    }
+}
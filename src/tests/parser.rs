@@ -99,6 +99,106 @@ mod from_str {
         );
     }
 
+    #[test]
+    fn valid_negated() {
+        assert_eq!(1, from_str::<TestFlags>("A | !B").unwrap().bits());
+        assert_eq!(
+            1 | 1 << 2,
+            from_str::<TestFlags>("A | B | C | !B").unwrap().bits()
+        );
+        assert_eq!(
+            1 | 1 << 2,
+            from_str::<TestFlags>("ABC | ! B").unwrap().bits()
+        );
+        assert_eq!(0, from_str::<TestFlags>("!A").unwrap().bits());
+        assert_eq!(0, from_str::<TestFlags>("!A | !0x8").unwrap().bits());
+
+        // Flags are removed from the flags before them, not after them
+        assert_eq!(1 << 1, from_str::<TestFlags>("!B | B").unwrap().bits());
+        assert_eq!(1, from_str::<TestFlags>("A | !B | B | !B").unwrap().bits());
+
+        assert_eq!(
+            1 | 1 << 1,
+            from_str::<TestFlags>("A | B | 0x8 | !0x8").unwrap().bits()
+        );
+        assert_eq!(1, from_str::<TestFlags>("0xf | !0b1110").unwrap().bits());
+
+        // Removing a multi-bit flag removes all of its bits, even ones shared with other flags
+        assert_eq!(1, from_str::<TestOverlapping>("AB | !BC").unwrap().bits());
+    }
+
+    #[test]
+    fn invalid_negated() {
+        assert_eq!(
+            ParseErrorKind::EmptyNegatedFlag,
+            *from_str::<TestFlags>("A | !").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseErrorKind::EmptyNegatedFlag,
+            *from_str::<TestFlags>("! | A").unwrap_err().kind()
+        );
+        assert_eq!(
+            "encountered `!` without a flag",
+            from_str::<TestFlags>("A | ! ").unwrap_err().to_string()
+        );
+        assert_eq!(
+            Some(4..5),
+            from_str::<TestFlags>("A | ! | B").unwrap_err().span()
+        );
+
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str::<TestFlags>("A | !!B").unwrap_err().kind()
+        );
+        assert_eq!(
+            Some("a"),
+            from_str::<TestFlags>("A | !a").unwrap_err().got()
+        );
+        assert_eq!(
+            Some(6..7),
+            from_str::<TestFlags>("A | ! a").unwrap_err().span()
+        );
+    }
+
+    #[test]
+    fn valid_hex_separators() {
+        assert_eq!(1 << 3, from_str::<TestFlags>("0x0_8").unwrap().bits());
+        assert_eq!(1 << 3, from_str::<TestFlags>("0x_8_").unwrap().bits());
+        assert_eq!(
+            1 << 3,
+            from_str::<TestFlags>("0x0000_0000_0008").unwrap().bits()
+        );
+        assert_eq!(0, from_str::<TestFlags>("0x0_0").unwrap().bits());
+        assert_eq!(
+            1 | 1 << 4,
+            from_str::<TestFlags>("A | 0x1_0").unwrap().bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 4 | 1 << 7,
+            from_str::<TestFlags>("A | 0x9_0 | B").unwrap().bits()
+        );
+
+        assert_eq!(0xdead_beef, u32::parse_hex("DEAD_BEEF").unwrap());
+        assert_eq!(
+            0xff,
+            u8::parse_hex("0000_0000_0000_0000_0000_0000_0000_0000_00ff").unwrap()
+        );
+    }
+
+    #[test]
+    fn valid_bin_oct() {
+        assert_eq!(1 << 3, from_str::<TestFlags>("0b1000").unwrap().bits());
+        assert_eq!(1 << 3, from_str::<TestFlags>("0o10").unwrap().bits());
+        assert_eq!(
+            1 | 1 << 1 | 1 << 3,
+            from_str::<TestFlags>("A | 0b0010 | 0x8").unwrap().bits()
+        );
+        assert_eq!(
+            1 << 1 | 1 << 3 | 1 << 4,
+            from_str::<TestFlags>("0b10|0o20|0x8").unwrap().bits()
+        );
+    }
+
     #[test]
     fn invalid() {
         assert!(from_str::<TestFlags>("a")
@@ -114,21 +214,648 @@ mod from_str {
             .unwrap_err()
             .to_string()
             .starts_with("invalid hex flag"));
+        assert!(from_str::<TestFlags>("0x__")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid hex flag"));
+        assert!(from_str::<TestFlags>("0x-_1")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid hex flag"));
+        assert!(from_str::<TestFlags>("A | 0x_ | B")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid hex flag"));
+        assert!(i8::parse_hex("-0_1").is_err());
         assert!(from_str::<TestFlags>("0xffffffffffff")
             .unwrap_err()
             .to_string()
             .starts_with("invalid hex flag"));
+
+        assert!(from_str::<TestFlags>("0b12")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid binary flag"));
+        assert!(from_str::<TestFlags>("0b111111111")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid binary flag"));
+
+        assert!(from_str::<TestFlags>("0o8")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid octal flag"));
+        assert!(from_str::<TestFlags>("0o400")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid octal flag"));
+    }
+
+    #[test]
+    fn invalid_kind() {
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str::<TestFlags>("A | a").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidHexFlag,
+            *from_str::<TestFlags>("0xg").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidBinFlag,
+            *from_str::<TestFlags>("0b2").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidOctFlag,
+            *from_str::<TestFlags>("0o8").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseErrorKind::EmptyFlag,
+            *from_str::<TestFlags>("A | | B").unwrap_err().kind()
+        );
+
+        assert_eq!(None, from_str::<TestFlags>("A | | B").unwrap_err().got());
+
+        assert_eq!(Some("a"), from_str::<TestFlags>("A | a").unwrap_err().got());
+        assert_eq!(Some("g"), from_str::<TestFlags>("0xg").unwrap_err().got());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid_got_long() {
+        let long = "ThisFlagNameIsLongerThanThirtyTwoBytes";
+        let err = from_str::<TestFlags>(long).unwrap_err();
+
+        assert_eq!(
+            "unrecognized named flag `ThisFlagNameIsLongerThanThirtyTwoBytes`",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn invalid_got_no_std() {
+        assert_eq!(
+            "unrecognized named flag `a`",
+            from_str::<TestFlags>("A | a").unwrap_err().to_string()
+        );
+
+        // Tokens are truncated at a char boundary without `std`
+        let err = from_str::<TestFlags>("ThisFlagNameIsLongerThanThirtyTwoBytes").unwrap_err();
+
+        assert_eq!(Some("ThisFlagNameIsLongerThanThirtyTw"), err.got());
+        assert_eq!(
+            "unrecognized named flag `ThisFlagNameIsLongerThanThirtyTw…`",
+            err.to_string()
+        );
+
+        let err = from_str::<TestFlags>("ÀÀÀÀÀÀÀÀÀÀÀÀÀÀÀÀÀ").unwrap_err();
+
+        assert_eq!(Some("ÀÀÀÀÀÀÀÀÀÀÀÀÀÀÀÀ"), err.got());
+    }
+
+    #[test]
+    fn invalid_span() {
+        assert_eq!(
+            Some(8..12),
+            from_str::<TestFlags>("A | B | typo | C")
+                .unwrap_err()
+                .span()
+        );
+        assert_eq!(
+            Some(2..5),
+            from_str::<TestFlags>("A|0xg").unwrap_err().span()
+        );
+        assert_eq!(
+            Some(4..4),
+            from_str::<TestFlags>("A | | B").unwrap_err().span()
+        );
+        assert_eq!(
+            Some(4..8),
+            from_str::<TestUnicode>("一|二x").unwrap_err().span()
+        );
+
+        assert_eq!(
+            Some(4..7),
+            from_str_strict::<TestFlags>("A | 0x1").unwrap_err().span()
+        );
+
+        assert_eq!(None, ParseError::empty_flag().span());
+    }
+}
+
+mod to_writer {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        assert_eq!("", write(TestFlags::empty()));
+        assert_eq!("A", write(TestFlags::A));
+        assert_eq!("A | B | C", write(TestFlags::all()));
+        assert_eq!("0x8", write(TestFlags::from_bits_retain(1 << 3)));
+        assert_eq!(
+            "A | 0x8",
+            write(TestFlags::A | TestFlags::from_bits_retain(1 << 3))
+        );
+
+        assert_eq!("", write(TestZero::ZERO));
+
+        assert_eq!("ABC", write(TestFlagsInvert::all()));
+
+        assert_eq!("0x1", write(TestOverlapping::from_bits_retain(1)));
+
+        assert_eq!("A", write(TestOverlappingFull::C));
+        assert_eq!(
+            "A | D",
+            write(TestOverlappingFull::C | TestOverlappingFull::D)
+        );
+    }
+
+    fn write<F: Flags>(value: F) -> String
+    where
+        F::Bits: crate::parser::WriteHex,
+    {
+        let mut s = String::new();
+
+        to_writer(&value, &mut s).unwrap();
+        s
+    }
+}
+
+mod from_str_with {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(0, from_str_with::<TestFlags>("", ",").unwrap().bits());
+
+        assert_eq!(1, from_str_with::<TestFlags>("A", ",").unwrap().bits());
+        assert_eq!(
+            1 | 1 << 1 | 1 << 2,
+            from_str_with::<TestFlags>("A, B ,C", ",").unwrap().bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 2,
+            from_str_with::<TestFlags>("A B C", " ").unwrap().bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 3,
+            from_str_with::<TestFlags>("A and B and 0x8", "and")
+                .unwrap()
+                .bits()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            ParseErrorKind::EmptySeparator,
+            *from_str_with::<TestFlags>("A | B", "").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str_with::<TestFlags>("A | B", ",").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseErrorKind::EmptyFlag,
+            *from_str_with::<TestFlags>("A,,B", ",").unwrap_err().kind()
+        );
+
+        assert_eq!(
+            Some(5..6),
+            from_str_with::<TestFlags>("A :: D", "::")
+                .unwrap_err()
+                .span()
+        );
+    }
+}
+
+mod from_str_qualified {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            0,
+            from_str_qualified::<TestFlags>("", "TestFlags")
+                .unwrap()
+                .bits()
+        );
+
+        assert_eq!(
+            1,
+            from_str_qualified::<TestFlags>("TestFlags::A", "TestFlags")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 3,
+            from_str_qualified::<TestFlags>("TestFlags::A | B | 0x8", "TestFlags")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1 << 2,
+            from_str_qualified::<TestFlags>("TestFlags::ABC | !TestFlags::A | !B", "TestFlags")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1 | 1 << 1,
+            from_str_qualified::<TestFlags>("A | B", "").unwrap().bits()
+        );
+        assert_eq!(
+            1,
+            from_str_qualified::<TestFlags>("a::TestFlags::A", "a::TestFlags")
+                .unwrap()
+                .bits()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            ParseErrorKind::MismatchedTypePrefix,
+            *from_str_qualified::<TestFlags>("A | Other::B", "TestFlags")
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            ParseErrorKind::MismatchedTypePrefix,
+            *from_str_qualified::<TestFlags>("TestFlagsInvert::A", "TestFlags")
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            ParseErrorKind::MismatchedTypePrefix,
+            *from_str_qualified::<TestFlags>("TestFlags::A", "")
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str_qualified::<TestFlags>("TestFlags::D", "TestFlags")
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str_qualified::<TestFlags>("TestFlags::TestFlags::A", "TestFlags")
+                .unwrap_err()
+                .kind()
+        );
+
+        let err = from_str_qualified::<TestFlags>("A | Other::B", "TestFlags").unwrap_err();
+        assert_eq!(Some("Other::B"), err.got());
+        assert_eq!(Some(4..12), err.span());
+        assert_eq!(
+            "named flag is qualified by a different type `Other::B`",
+            err.to_string()
+        );
+
+        // Qualified names aren't accepted by the other parsers
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str::<TestFlags>("TestFlags::A").unwrap_err().kind()
+        );
+    }
+}
+
+mod to_writer_with {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        assert_eq!("", write(TestFlags::empty(), ","));
+        assert_eq!("A", write(TestFlags::A, ","));
+        assert_eq!("A,B,C", write(TestFlags::all(), ","));
+        assert_eq!("A B C", write(TestFlags::all(), " "));
+        assert_eq!(
+            "A, 0x8",
+            write(TestFlags::A | TestFlags::from_bits_retain(1 << 3), ", ")
+        );
+        assert_eq!("ABC", write(TestFlags::all(), ""));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let f = TestFlags::A | TestFlags::C | TestFlags::from_bits_retain(1 << 4);
+
+        assert_eq!(f, from_str_with(&write(f, ", "), ",").unwrap());
+    }
+
+    fn write<F: Flags>(value: F, separator: &str) -> String
+    where
+        F::Bits: crate::parser::WriteHex,
+    {
+        let mut s = String::new();
+
+        to_writer_with(&value, &mut s, separator).unwrap();
+        s
+    }
+}
+
+mod to_writer_with_options {
+    use super::*;
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        struct TestWide: u64 {
+            const A = 1;
+        }
+    }
+
+    #[test]
+    fn cases() {
+        assert_eq!(
+            "A | 0x8",
+            write(
+                TestFlags::A | TestFlags::from_bits_retain(1 << 3),
+                FmtOptions::new()
+            )
+        );
+
+        assert_eq!(
+            "0xfffe",
+            write(
+                TestWide::from_bits_retain(0xfffe),
+                FmtOptions::new().group_hex(true)
+            )
+        );
+        assert_eq!(
+            "0x1_0000",
+            write(
+                TestWide::from_bits_retain(0x1_0000),
+                FmtOptions::new().group_hex(true)
+            )
+        );
+        assert_eq!(
+            "A | 0xdead_beee",
+            write(
+                TestWide::from_bits_retain(0xdead_beef),
+                FmtOptions::new().group_hex(true)
+            )
+        );
+        assert_eq!(
+            "0xFFFF_FFFF_FFFF_FFFE",
+            write(
+                TestWide::from_bits_retain(!1),
+                FmtOptions::new().group_hex(true).uppercase_hex(true)
+            )
+        );
+        assert_eq!(
+            "0xDEADBEEE",
+            write(
+                TestWide::from_bits_retain(0xdead_beee),
+                FmtOptions::new().uppercase_hex(true)
+            )
+        );
+    }
+
+    #[test]
+    fn empty_token() {
+        assert_eq!("", write(TestFlags::empty(), FmtOptions::new()));
+        assert_eq!(
+            "<empty>",
+            write(TestFlags::empty(), FmtOptions::new().empty_token("<empty>"))
+        );
+        assert_eq!(
+            "A",
+            write(TestFlags::A, FmtOptions::new().empty_token("<empty>"))
+        );
+        assert_eq!(
+            "0x8",
+            write(
+                TestFlags::from_bits_retain(1 << 3),
+                FmtOptions::new().empty_token("<empty>")
+            )
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let options = FmtOptions::new().group_hex(true).uppercase_hex(true);
+
+        for bits in [0, 1, 0xf, 0xffff, 0x1_0000, 0xdead_beef, !0] {
+            let f = TestWide::from_bits_retain(bits);
+
+            assert_eq!(f, from_str(&write(f, options)).unwrap());
+        }
+    }
+
+    fn write<F: Flags>(value: F, options: FmtOptions) -> String
+    where
+        F::Bits: crate::parser::WriteHex,
+    {
+        let mut s = String::new();
+
+        to_writer_with_options(&value, &mut s, options).unwrap();
+        s
+    }
+}
+
+mod from_str_with_options {
+    use super::*;
+
+    #[test]
+    fn empty_token() {
+        let options = ParseOptions::new().empty_token("<empty>");
+
+        assert_eq!(
+            0,
+            from_str_with_options::<TestFlags>("<empty>", options)
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            0,
+            from_str_with_options::<TestFlags>(" <empty> ", options)
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            0,
+            from_str_with_options::<TestFlags>("", options)
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1,
+            from_str_with_options::<TestFlags>("A", options)
+                .unwrap()
+                .bits()
+        );
+
+        // The token is only recognized on its own
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str_with_options::<TestFlags>("A | <empty>", options)
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn no_empty_token() {
+        let options = ParseOptions::new();
+
+        assert_eq!(
+            0,
+            from_str_with_options::<TestFlags>("", options)
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *from_str_with_options::<TestFlags>("<empty>", options)
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let fmt = FmtOptions::new().empty_token("-");
+        let parse = ParseOptions::new().empty_token("-");
+
+        for bits in [0, 1, 1 | 1 << 2, 1 << 3] {
+            let flags = TestFlags::from_bits_retain(bits);
+
+            let mut s = String::new();
+            to_writer_with_options(&flags, &mut s, fmt).unwrap();
+
+            assert_eq!(flags, from_str_with_options(&s, parse).unwrap(), "{:?}", s);
+        }
+    }
+}
+
+mod derive_from_str {
+    use super::*;
+
+    use core::convert::TryFrom;
+
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[bitflags(derive_from_str)]
+        struct Flags: u8 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Flags::empty(), "".parse::<Flags>().unwrap());
+        assert_eq!(Flags::A | Flags::B, "A | B".parse::<Flags>().unwrap());
+        assert_eq!(
+            Flags::A | Flags::from_bits_retain(1 << 3),
+            "A | 0x8".parse::<Flags>().unwrap()
+        );
+
+        assert_eq!(
+            ParseErrorKind::InvalidNamedFlag,
+            *"C".parse::<Flags>().unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(Flags::A | Flags::B, Flags::try_from("A | B").unwrap());
+        assert_eq!(Flags::B, Flags::try_from(" B ").unwrap());
+
+        assert_eq!(
+            ParseErrorKind::InvalidHexFlag,
+            *Flags::try_from("0xg").unwrap_err().kind()
+        );
+    }
+}
+
+mod from_str_truncate {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(0, from_str_truncate::<TestFlags>("").unwrap().bits());
+
+        assert_eq!(1, from_str_truncate::<TestFlags>("A").unwrap().bits());
+        assert_eq!(1, from_str_truncate::<TestFlags>(" A ").unwrap().bits());
+        assert_eq!(
+            1 | 1 << 1 | 1 << 2,
+            from_str_truncate::<TestFlags>("A | B | C").unwrap().bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 2,
+            from_str_truncate::<TestFlags>("A\n|\tB\r\n|   C ")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1 | 1 << 1 | 1 << 2,
+            from_str_truncate::<TestFlags>("A|B|C").unwrap().bits()
+        );
+
+        assert_eq!(0, from_str_truncate::<TestFlags>("0x8").unwrap().bits());
+        assert_eq!(1, from_str_truncate::<TestFlags>("A | 0x8").unwrap().bits());
+        assert_eq!(
+            1 | 1 << 1,
+            from_str_truncate::<TestFlags>("0x1 | 0x8 | B")
+                .unwrap()
+                .bits()
+        );
+
+        assert_eq!(
+            1 | 1 << 1,
+            from_str_truncate::<TestUnicode>("一 | 二").unwrap().bits()
+        );
+    }
+}
+
+mod to_writer_truncate {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        assert_eq!("", write(TestFlags::empty()));
+        assert_eq!("A", write(TestFlags::A));
+        assert_eq!("A | B | C", write(TestFlags::all()));
+        assert_eq!("", write(TestFlags::from_bits_retain(1 << 3)));
+        assert_eq!(
+            "A",
+            write(TestFlags::A | TestFlags::from_bits_retain(1 << 3))
+        );
+
+        assert_eq!("", write(TestZero::ZERO));
+
+        assert_eq!("ABC", write(TestFlagsInvert::all()));
+
+        assert_eq!("0x1", write(TestOverlapping::from_bits_retain(1)));
+
+        assert_eq!("A", write(TestOverlappingFull::C));
+        assert_eq!(
+            "A | D",
+            write(TestOverlappingFull::C | TestOverlappingFull::D)
+        );
+    }
+
+    fn write<F: Flags>(value: F) -> String
+    where
+        F::Bits: crate::parser::WriteHex,
+    {
+        let mut s = String::new();
+
+        to_writer_truncate(&value, &mut s).unwrap();
+        s
     }
 }
 
-mod to_writer {
+mod to_writer_verbose {
     use super::*;
 
     #[test]
     fn cases() {
         assert_eq!("", write(TestFlags::empty()));
         assert_eq!("A", write(TestFlags::A));
-        assert_eq!("A | B | C", write(TestFlags::all()));
+        assert_eq!("A | B | C | ABC", write(TestFlags::all()));
         assert_eq!("0x8", write(TestFlags::from_bits_retain(1 << 3)));
         assert_eq!(
             "A | 0x8",
@@ -137,15 +864,28 @@ mod to_writer {
 
         assert_eq!("", write(TestZero::ZERO));
 
-        assert_eq!("ABC", write(TestFlagsInvert::all()));
+        assert_eq!("AB | BC", write(TestOverlapping::from_bits_retain(0b111)));
+        assert_eq!("0x2", write(TestOverlapping::from_bits_retain(1 << 1)));
+    }
 
-        assert_eq!("0x1", write(TestOverlapping::from_bits_retain(1)));
+    #[test]
+    fn overlapping() {
+        let flags = TestOverlappingFull::C | TestOverlappingFull::D;
 
-        assert_eq!("A", write(TestOverlappingFull::C));
-        assert_eq!(
-            "A | D",
-            write(TestOverlappingFull::C | TestOverlappingFull::D)
-        );
+        let mut s = String::new();
+        to_writer(&flags, &mut s).unwrap();
+        assert_eq!("A | D", s);
+
+        let verbose = write(flags);
+        assert_eq!("A | B | C | D", verbose);
+
+        // The verbose text parses to the same value, but isn't canonical
+        let parsed = from_str::<TestOverlappingFull>(&verbose).unwrap();
+        assert_eq!(flags, parsed);
+
+        s.clear();
+        to_writer(&parsed, &mut s).unwrap();
+        assert_eq!("A | D", s);
     }
 
     fn write<F: Flags>(value: F) -> String
@@ -154,86 +894,164 @@ mod to_writer {
     {
         let mut s = String::new();
 
-        to_writer(&value, &mut s).unwrap();
+        to_writer_verbose(&value, &mut s).unwrap();
         s
     }
 }
 
-mod from_str_truncate {
+mod from_str_case_insensitive {
     use super::*;
 
+    bitflags! {
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        struct TestCase: u8 {
+            const Read = 1;
+            const READ = 1 << 1;
+            const WRITE = 1 << 2;
+        }
+    }
+
     #[test]
     fn valid() {
-        assert_eq!(0, from_str_truncate::<TestFlags>("").unwrap().bits());
+        assert_eq!(
+            0,
+            from_str_case_insensitive::<TestFlags>("").unwrap().bits()
+        );
 
-        assert_eq!(1, from_str_truncate::<TestFlags>("A").unwrap().bits());
-        assert_eq!(1, from_str_truncate::<TestFlags>(" A ").unwrap().bits());
         assert_eq!(
-            1 | 1 << 1 | 1 << 2,
-            from_str_truncate::<TestFlags>("A | B | C").unwrap().bits()
+            1,
+            from_str_case_insensitive::<TestFlags>("a").unwrap().bits()
         );
         assert_eq!(
             1 | 1 << 1 | 1 << 2,
-            from_str_truncate::<TestFlags>("A\n|\tB\r\n|   C ")
+            from_str_case_insensitive::<TestFlags>("a | B | c")
                 .unwrap()
                 .bits()
         );
         assert_eq!(
             1 | 1 << 1 | 1 << 2,
-            from_str_truncate::<TestFlags>("A|B|C").unwrap().bits()
+            from_str_case_insensitive::<TestFlags>("abc")
+                .unwrap()
+                .bits()
         );
 
-        assert_eq!(0, from_str_truncate::<TestFlags>("0x8").unwrap().bits());
-        assert_eq!(1, from_str_truncate::<TestFlags>("A | 0x8").unwrap().bits());
         assert_eq!(
-            1 | 1 << 1,
-            from_str_truncate::<TestFlags>("0x1 | 0x8 | B")
+            1 | 1 << 3,
+            from_str_case_insensitive::<TestFlags>("a | 0x8")
                 .unwrap()
                 .bits()
         );
 
         assert_eq!(
-            1 | 1 << 1,
-            from_str_truncate::<TestUnicode>("一 | 二").unwrap().bits()
+            1,
+            from_str_case_insensitive::<TestCase>("read")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1,
+            from_str_case_insensitive::<TestCase>("READ")
+                .unwrap()
+                .bits()
+        );
+        assert_eq!(
+            1 | 1 << 2,
+            from_str_case_insensitive::<TestCase>("read|write")
+                .unwrap()
+                .bits()
         );
     }
+
+    #[test]
+    fn invalid() {
+        assert!(from_str_case_insensitive::<TestFlags>("d")
+            .unwrap_err()
+            .to_string()
+            .starts_with("unrecognized named flag"));
+        assert!(from_str_case_insensitive::<TestFlags>("0xg")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid hex flag"));
+        assert!(from_str_case_insensitive::<TestExternal>("_")
+            .unwrap_err()
+            .to_string()
+            .starts_with("unrecognized named flag"));
+    }
 }
 
-mod to_writer_truncate {
+mod from_str_lossy {
     use super::*;
 
     #[test]
-    fn cases() {
-        assert_eq!("", write(TestFlags::empty()));
-        assert_eq!("A", write(TestFlags::A));
-        assert_eq!("A | B | C", write(TestFlags::all()));
-        assert_eq!("", write(TestFlags::from_bits_retain(1 << 3)));
-        assert_eq!(
-            "A",
-            write(TestFlags::A | TestFlags::from_bits_retain(1 << 3))
-        );
+    #[cfg(feature = "std")]
+    fn valid() {
+        let (flags, skipped) = from_str_lossy::<TestFlags>("").unwrap();
+        assert_eq!(0, flags.bits());
+        assert!(skipped.is_empty());
 
-        assert_eq!("", write(TestZero::ZERO));
+        let (flags, skipped) = from_str_lossy::<TestFlags>("A | B").unwrap();
+        assert_eq!(1 | 1 << 1, flags.bits());
+        assert!(skipped.is_empty());
 
-        assert_eq!("ABC", write(TestFlagsInvert::all()));
+        let (flags, skipped) = from_str_lossy::<TestFlags>("A | D | 0x8 | E | C").unwrap();
+        assert_eq!(1 | 1 << 2 | 1 << 3, flags.bits());
+        assert_eq!(vec!["D", "E"], skipped);
 
-        assert_eq!("0x1", write(TestOverlapping::from_bits_retain(1)));
+        let (flags, skipped) = from_str_lossy::<TestFlags>("a").unwrap();
+        assert_eq!(0, flags.bits());
+        assert_eq!(vec!["a"], skipped);
+    }
 
-        assert_eq!("A", write(TestOverlappingFull::C));
+    #[test]
+    fn valid_count() {
         assert_eq!(
-            "A | D",
-            write(TestOverlappingFull::C | TestOverlappingFull::D)
+            (0, 0),
+            from_str_lossy_count::<TestFlags>("")
+                .map(|(flags, skipped)| (flags.bits(), skipped))
+                .unwrap()
+        );
+        assert_eq!(
+            (1 | 1 << 2 | 1 << 3, 2),
+            from_str_lossy_count::<TestFlags>("A | D | 0x8 | E | C")
+                .map(|(flags, skipped)| (flags.bits(), skipped))
+                .unwrap()
+        );
+        assert_eq!(
+            (1 << 1 | 1 << 4, 1),
+            from_str_lossy_count::<TestFlags>("0b10 | F | 0o20")
+                .map(|(flags, skipped)| (flags.bits(), skipped))
+                .unwrap()
         );
     }
 
-    fn write<F: Flags>(value: F) -> String
-    where
-        F::Bits: crate::parser::WriteHex,
-    {
-        let mut s = String::new();
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            ParseErrorKind::InvalidHexFlag,
+            *from_str_lossy_count::<TestFlags>("D | 0xg")
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            ParseErrorKind::InvalidBinFlag,
+            *from_str_lossy_count::<TestFlags>("A | 0b2")
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            ParseErrorKind::EmptyFlag,
+            *from_str_lossy_count::<TestFlags>("A || D")
+                .unwrap_err()
+                .kind()
+        );
 
-        to_writer_truncate(&value, &mut s).unwrap();
-        s
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(
+                ParseErrorKind::InvalidHexFlag,
+                *from_str_lossy::<TestFlags>("D | 0xg").unwrap_err().kind()
+            );
+        }
     }
 }
 
@@ -330,3 +1148,309 @@ mod to_writer_strict {
         s
     }
 }
+
+mod try_to_writer_strict {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        assert_eq!("", write(TestFlags::empty()).unwrap());
+        assert_eq!("A", write(TestFlags::A).unwrap());
+        assert_eq!("A | B | C", write(TestFlags::all()).unwrap());
+
+        assert_eq!("", write(TestZero::ZERO).unwrap());
+
+        assert_eq!("ABC", write(TestFlagsInvert::all()).unwrap());
+
+        assert_eq!(
+            "A | D",
+            write(TestOverlappingFull::C | TestOverlappingFull::D).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            WriteErrorKind::UnnamedBits,
+            *write(TestFlags::from_bits_retain(1 << 3))
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            WriteErrorKind::UnnamedBits,
+            *write(TestFlags::A | TestFlags::from_bits_retain(1 << 3))
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            WriteErrorKind::UnnamedBits,
+            *write(TestOverlapping::from_bits_retain(1))
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            WriteErrorKind::UnnamedBits,
+            *write(TestExternal::all()).unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn invalid_writes_nothing() {
+        let mut s = String::new();
+
+        assert!(try_to_writer_strict(
+            &(TestFlags::A | TestFlags::from_bits_retain(1 << 3)),
+            &mut s
+        )
+        .is_err());
+        assert_eq!("", s);
+    }
+
+    fn write<F: Flags>(value: F) -> Result<String, WriteError> {
+        let mut s = String::new();
+
+        try_to_writer_strict(&value, &mut s)?;
+        Ok(s)
+    }
+}
+
+mod byte_array_hex {
+    use super::*;
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!([0x00, 0x00], <[u8; 2]>::parse_hex("0").unwrap());
+        assert_eq!([0x00, 0x01], <[u8; 2]>::parse_hex("1").unwrap());
+        assert_eq!([0x01, 0x00], <[u8; 2]>::parse_hex("100").unwrap());
+        assert_eq!([0x12, 0x34], <[u8; 2]>::parse_hex("1234").unwrap());
+        assert_eq!([0xab, 0xcd], <[u8; 2]>::parse_hex("AbCd").unwrap());
+        assert_eq!([0x12, 0x34], <[u8; 2]>::parse_hex("12_34").unwrap());
+        assert_eq!([0x00, 0x01], <[u8; 2]>::parse_hex("_1_").unwrap());
+
+        // Leading zeros don't count towards the length, like the integer impls
+        assert_eq!([0x00, 0x01], <[u8; 2]>::parse_hex("00_01").unwrap());
+        assert_eq!([0x00, 0x01], <[u8; 2]>::parse_hex("0000_0001").unwrap());
+        assert_eq!([0x12, 0x34], <[u8; 2]>::parse_hex("001234").unwrap());
+        assert_eq!([0x00, 0x00], <[u8; 2]>::parse_hex("0_0000").unwrap());
+
+        let mut expected = [0; 32];
+        expected[0] = 0x80;
+        assert_eq!(
+            expected,
+            <[u8; 32]>::parse_hex(&format!("80{}", "0".repeat(62))).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for input in [
+            "",
+            "x",
+            "12z4",
+            "-1",
+            "+1",
+            "12345",
+            "_",
+            "____",
+            "1_2_3_4_5",
+            "1_0000",
+            "12_z4",
+        ] {
+            assert_eq!(
+                ParseErrorKind::InvalidHexFlag,
+                *<[u8; 2]>::parse_hex(input).unwrap_err().kind(),
+                "{:?}",
+                input
+            );
+        }
+
+        assert_eq!([0u8; 0], <[u8; 0]>::parse_hex("0").unwrap());
+        assert!(<[u8; 0]>::parse_hex("1").is_err());
+    }
+
+    #[test]
+    fn write() {
+        assert_eq!("0", hex([0x00, 0x00]));
+        assert_eq!("1", hex([0x00, 0x01]));
+        assert_eq!("100", hex([0x01, 0x00]));
+        assert_eq!("1234", hex([0x12, 0x34]));
+        assert_eq!("0", hex([]));
+    }
+
+    #[test]
+    fn roundtrip() {
+        for a in 0u8..=255 {
+            for b in [0x00, 0x01, 0x10, 0xff] {
+                let bytes = [b, a, b];
+
+                assert_eq!(bytes, <[u8; 3]>::parse_hex(&hex(bytes)).unwrap());
+            }
+        }
+    }
+
+    fn hex<const N: usize>(value: [u8; N]) -> String {
+        let mut s = String::new();
+
+        value.write_hex(&mut s).unwrap();
+        s
+    }
+}
+
+mod custom_bits {
+    use super::*;
+
+    use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+    use crate::{Bits, Flag};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Word(u16);
+
+    impl Bits for Word {
+        const EMPTY: Self = Word(0);
+        const ALL: Self = Word(u16::MAX);
+    }
+
+    impl BitAnd for Word {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Word(self.0 & other.0)
+        }
+    }
+
+    impl BitOr for Word {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Word(self.0 | other.0)
+        }
+    }
+
+    impl BitXor for Word {
+        type Output = Self;
+
+        fn bitxor(self, other: Self) -> Self {
+            Word(self.0 ^ other.0)
+        }
+    }
+
+    impl Not for Word {
+        type Output = Self;
+
+        fn not(self) -> Self {
+            Word(!self.0)
+        }
+    }
+
+    impl ParseHex for Word {
+        fn parse_hex(input: &str) -> Result<Self, ParseError> {
+            u16::from_str_radix(input, 16)
+                .map(Word)
+                .map_err(|_| ParseError::invalid_hex_flag(input))
+        }
+
+        fn parse_bin(input: &str) -> Result<Self, ParseError> {
+            u16::from_str_radix(input, 2)
+                .map(Word)
+                .map_err(|_| ParseError::invalid_bin_flag(input))
+        }
+
+        // `parse_oct` is left to its default implementation, which fails
+    }
+
+    impl WriteHex for Word {
+        fn write_hex<W: core::fmt::Write>(&self, mut writer: W) -> core::fmt::Result {
+            write!(writer, "{:x}", self.0)
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct WordFlags(Word);
+
+    impl WordFlags {
+        const A: Self = WordFlags(Word(1));
+        const B: Self = WordFlags(Word(1 << 1));
+    }
+
+    impl Flags for WordFlags {
+        const FLAGS: &'static [Flag<Self>] =
+            &[Flag::new("A", WordFlags::A), Flag::new("B", WordFlags::B)];
+
+        type Bits = Word;
+
+        fn bits(&self) -> Word {
+            self.0
+        }
+
+        fn from_bits_retain(bits: Word) -> Self {
+            WordFlags(bits)
+        }
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(WordFlags(Word(0)), from_str::<WordFlags>("").unwrap());
+        assert_eq!(WordFlags(Word(0x1)), from_str::<WordFlags>("0x1").unwrap());
+        assert_eq!(
+            WordFlags(Word(0xf003)),
+            from_str::<WordFlags>("A | B | 0xf000").unwrap()
+        );
+        assert_eq!(
+            WordFlags(Word(0b101)),
+            from_str::<WordFlags>("0b100 | A").unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            ParseError::invalid_hex_flag("fffff").kind(),
+            from_str::<WordFlags>("0xfffff").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseError::invalid_hex_flag("g").kind(),
+            from_str::<WordFlags>("A | 0xg").unwrap_err().kind()
+        );
+        assert_eq!(
+            ParseError::invalid_oct_flag("7").kind(),
+            from_str::<WordFlags>("A | 0o7").unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn cases() {
+        assert_eq!("", write(WordFlags(Word(0))));
+        assert_eq!("A | B", write(WordFlags(Word(0b11))));
+        assert_eq!("A | 0xf000", write(WordFlags(Word(0xf001))));
+        assert_eq!("0xf0", write(WordFlags(Word(0xf0))));
+
+        let mut formatted = String::new();
+        to_writer_with_options(
+            &WordFlags(Word(0xabc1)),
+            &mut formatted,
+            FmtOptions::new().group_hex(true).uppercase_hex(true),
+        )
+        .unwrap();
+        assert_eq!("A | 0xABC0", formatted);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for bits in [0, 1, 0b11, 0xf001, u16::MAX] {
+            let flags = WordFlags(Word(bits));
+
+            assert_eq!(
+                flags,
+                from_str::<WordFlags>(&write(WordFlags(Word(bits)))).unwrap()
+            );
+        }
+    }
+
+    fn write(value: WordFlags) -> String {
+        let mut s = String::new();
+
+        to_writer(&value, &mut s).unwrap();
+        s
+    }
+}
@@ -96,5 +96,26 @@ fn case<T: Flags + std::fmt::Debug + Copy>(
             value,
             input
         );
+
+        assert_eq!(
+            (*expected != value.bits(), *expected),
+            {
+                let mut value = value;
+                (Flags::remove_checked(&mut value, *input), value.bits())
+            },
+            "Flags::remove_checked({:?}, {:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            (*expected != value.bits(), *expected),
+            {
+                let mut value = value;
+                (Flags::set_checked(&mut value, *input, false), value.bits())
+            },
+            "Flags::set_checked({:?}, {:?}, false)",
+            value,
+            input
+        );
     }
 }
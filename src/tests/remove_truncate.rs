@@ -0,0 +1,68 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::all() | TestFlags::from_bits_retain(1 << 3),
+        &[
+            (TestFlags::A, 1 << 1 | 1 << 2 | 1 << 3),
+            (TestFlags::empty(), 1 | 1 << 1 | 1 << 2 | 1 << 3),
+            (
+                TestFlags::from_bits_retain(1 << 3),
+                1 | 1 << 1 | 1 << 2 | 1 << 3,
+            ),
+            (
+                TestFlags::B | TestFlags::from_bits_retain(1 << 3),
+                1 | 1 << 2 | 1 << 3,
+            ),
+        ],
+    );
+
+    case(
+        TestFlags::A,
+        &[
+            (TestFlags::A, 0),
+            (TestFlags::ABC | TestFlags::from_bits_retain(!0), 0),
+        ],
+    );
+
+    case(
+        TestExternal::from_bits_retain(1 << 5),
+        &[(TestExternal::from_bits_retain(1 << 5), 0)],
+    );
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(value: T, inputs: &[(T, T::Bits)])
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                value.remove_truncate(*input);
+                value
+            }
+            .bits(),
+            "{:?}.remove_truncate({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            {
+                let mut value = value;
+                value.set_truncate(*input, false);
+                value
+            }
+            .bits(),
+            "{:?}.set_truncate({:?}, false)",
+            value,
+            input
+        );
+    }
+}
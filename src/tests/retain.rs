@@ -0,0 +1,64 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(TestFlags::empty(), |_| false, 0);
+    case(TestFlags::all(), |_| true, 1 | 1 << 1 | 1 << 2);
+    case(TestFlags::all(), |_| false, 0);
+    case(TestFlags::all(), |flag| flag != TestFlags::B, 1 | 1 << 2);
+    case(
+        TestFlags::A | TestFlags::B | TestFlags::from_bits_retain(1 << 3),
+        |flag| flag == TestFlags::B,
+        1 << 1 | 1 << 3,
+    );
+    case(TestFlags::from_bits_retain(1 << 3), |_| false, 1 << 3);
+
+    case(
+        TestOverlapping::AB | TestOverlapping::BC,
+        |flag| flag == TestOverlapping::BC,
+        1 << 1 | 1 << 2,
+    );
+    case(
+        TestOverlapping::AB | TestOverlapping::BC,
+        |flag| flag == TestOverlapping::AB,
+        1 | 1 << 1,
+    );
+    case(TestOverlapping::AB | TestOverlapping::BC, |_| false, 0);
+
+    case(TestZero::ZERO, |_| false, 0);
+
+    case(TestExternal::all(), |flag| flag != TestExternal::A, !1);
+}
+
+#[test]
+fn calls() {
+    let mut seen = Vec::new();
+
+    let mut flags = TestFlags::A | TestFlags::C | TestFlags::from_bits_retain(1 << 3);
+    flags.retain(|flag| {
+        seen.push(flag);
+        true
+    });
+
+    assert_eq!(vec![TestFlags::A, TestFlags::C], seen);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(value: T, f: impl FnMut(T) -> bool, expected: T::Bits)
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    assert_eq!(
+        expected,
+        {
+            let mut value = value;
+            value.retain(f);
+            value
+        }
+        .bits(),
+        "{:?}.retain(..)",
+        value,
+    );
+}
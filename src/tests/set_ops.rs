@@ -0,0 +1,83 @@
+use super::*;
+
+use crate::Flags;
+
+// Every pair of values over the defined bits of `TestFlags`, plus an unknown bit
+const BITS: core::ops::RangeInclusive<u8> = 0..=0b1111;
+
+#[test]
+fn binary_ops_retain_unknown_bits() {
+    for a in BITS {
+        for b in BITS {
+            let (fa, fb) = (
+                TestFlags::from_bits_retain(a),
+                TestFlags::from_bits_retain(b),
+            );
+
+            case(a | b, fa.union(fb), "union", a, b);
+            case(a | b, fa | fb, "|", a, b);
+            case(a | b, Flags::union(fa, fb), "Flags::union", a, b);
+            case(a | b, with_mut(fa, |f| f.insert(fb)), "insert", a, b);
+
+            case(a & b, fa.intersection(fb), "intersection", a, b);
+            case(a & b, fa & fb, "&", a, b);
+            case(
+                a & b,
+                Flags::intersection(fa, fb),
+                "Flags::intersection",
+                a,
+                b,
+            );
+
+            case(
+                a ^ b,
+                fa.symmetric_difference(fb),
+                "symmetric_difference",
+                a,
+                b,
+            );
+            case(a ^ b, fa ^ fb, "^", a, b);
+            case(
+                a ^ b,
+                Flags::symmetric_difference(fa, fb),
+                "Flags::symmetric_difference",
+                a,
+                b,
+            );
+            case(a ^ b, with_mut(fa, |f| f.toggle(fb)), "toggle", a, b);
+
+            // The target isn't truncated, so unknown bits in it are removed from the source
+            case(a & !b, fa.difference(fb), "difference", a, b);
+            case(a & !b, fa - fb, "-", a, b);
+            case(a & !b, Flags::difference(fa, fb), "Flags::difference", a, b);
+            case(a & !b, with_mut(fa, |f| f.remove(fb)), "remove", a, b);
+        }
+    }
+}
+
+#[test]
+fn unary_ops() {
+    let all = TestFlags::all().bits();
+
+    for a in BITS {
+        let fa = TestFlags::from_bits_retain(a);
+
+        // `complement` and `!` truncate, `complement_retain` doesn't
+        case(!a & all, fa.complement(), "complement", a, a);
+        case(!a & all, !fa, "!", a, a);
+        case(!a & all, Flags::complement(fa), "Flags::complement", a, a);
+        case(!a, fa.complement_retain(), "complement_retain", a, a);
+
+        case(a & all, with_mut(fa, |f| f.truncate()), "truncate", a, a);
+    }
+}
+
+fn with_mut(mut value: TestFlags, f: impl FnOnce(&mut TestFlags)) -> TestFlags {
+    f(&mut value);
+    value
+}
+
+#[track_caller]
+fn case(expected: u8, value: TestFlags, op: &str, a: u8, b: u8) {
+    assert_eq!(expected, value.bits(), "{}({:#06b}, {:#06b})", op, a, b);
+}
@@ -0,0 +1,109 @@
+use crate::{Bits, Flags};
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct TestSigned: i8 {
+        const A = 1;
+        const B = 1 << 1;
+        const SIGN = i8::MIN;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct TestSignedAll: i8 {
+        const A = 1;
+        const _ = !0;
+    }
+}
+
+#[test]
+fn bits_constants() {
+    assert_eq!(0, <i8 as Bits>::EMPTY);
+    assert_eq!(-1, <i8 as Bits>::ALL);
+    assert_eq!(<i8 as Bits>::ALL, !<i8 as Bits>::EMPTY);
+}
+
+#[test]
+fn all() {
+    assert_eq!(1 | 1 << 1 | i8::MIN, TestSigned::all().bits());
+    assert_eq!(-1, TestSignedAll::all().bits());
+
+    assert!(TestSigned::SIGN.bits() < 0);
+    assert!(TestSigned::all().contains(TestSigned::SIGN));
+}
+
+// Every value of `i8`, including negative values with the sign bit set
+#[test]
+fn exhaustive() {
+    let all = TestSigned::all().bits();
+
+    for a in i8::MIN..=i8::MAX {
+        let fa = TestSigned::from_bits_retain(a);
+
+        case(a, fa, "from_bits_retain", a);
+        case(
+            a & all,
+            TestSigned::from_bits_truncate(a),
+            "from_bits_truncate",
+            a,
+        );
+        assert_eq!(
+            a & !all == 0,
+            TestSigned::from_bits(a).is_some(),
+            "from_bits({:#010b})",
+            a
+        );
+        assert_eq!(
+            a & !all != 0,
+            fa.contains_unknown_bits(),
+            "contains_unknown_bits({:#010b})",
+            a
+        );
+
+        // `complement` and `!` truncate, so sign extension can't introduce unknown bits
+        case(!a & all, fa.complement(), "complement", a);
+        case(!a & all, !fa, "!", a);
+        case(!a & all, Flags::complement(fa), "Flags::complement", a);
+        case(!a, fa.complement_retain(), "complement_retain", a);
+        case(
+            !a & all,
+            {
+                let mut fa = fa;
+                fa.toggle_all();
+                fa
+            },
+            "toggle_all",
+            a,
+        );
+
+        case(a & all, fa & TestSigned::all(), "& all", a);
+        case(a | all, fa | TestSigned::all(), "| all", a);
+        case(
+            !(a & all) & all,
+            !TestSigned::from_bits_truncate(a),
+            "!truncate",
+            a,
+        );
+
+        assert_eq!(
+            a == all,
+            fa.is_all() && !fa.contains_unknown_bits(),
+            "is_all({:#010b})",
+            a
+        );
+
+        // With every bit known, truncating and complementing keep all bits
+        let fa = TestSignedAll::from_bits_retain(a);
+
+        assert_eq!(a, TestSignedAll::from_bits_truncate(a).bits());
+        assert_eq!(Some(fa), TestSignedAll::from_bits(a));
+        assert_eq!(!a, (!fa).bits());
+        assert_eq!(!a, fa.complement().bits());
+    }
+}
+
+#[track_caller]
+fn case(expected: i8, value: TestSigned, op: &str, a: i8) {
+    assert_eq!(expected, value.bits(), "{}({:#010b})", op, a);
+}
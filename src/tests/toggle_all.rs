@@ -0,0 +1,71 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(0, TestFlags::all(), TestFlags::toggle_all);
+    case(
+        1 | 1 << 1 | 1 << 2,
+        TestFlags::empty(),
+        TestFlags::toggle_all,
+    );
+    case(1 | 1 << 1, TestFlags::C, TestFlags::toggle_all);
+    case(
+        1 | 1 << 1,
+        TestFlags::C | TestFlags::from_bits_retain(1 << 3),
+        TestFlags::toggle_all,
+    );
+
+    case(0, TestZero::empty(), TestZero::toggle_all);
+
+    case(0, TestEmpty::empty(), TestEmpty::toggle_all);
+
+    case(1 << 2, TestOverlapping::AB, TestOverlapping::toggle_all);
+
+    case(!0, TestExternal::empty(), TestExternal::toggle_all);
+    case(
+        !(1 << 5),
+        TestExternal::from_bits_retain(1 << 5),
+        TestExternal::toggle_all,
+    );
+}
+
+#[test]
+fn twice() {
+    for bits in 0..=0b111 {
+        let value = TestFlags::from_bits_retain(bits);
+
+        let mut toggled = value;
+        toggled.toggle_all();
+        toggled.toggle_all();
+
+        assert_eq!(value, toggled, "{:?}.toggle_all().toggle_all()", value);
+    }
+
+    // Unknown bits are removed on the first toggle
+    let mut toggled = TestFlags::A | TestFlags::from_bits_retain(1 << 3);
+    toggled.toggle_all();
+    toggled.toggle_all();
+
+    assert_eq!(TestFlags::A, toggled);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    expected: T::Bits,
+    value: T,
+    inherent: impl FnOnce(&mut T),
+) where
+    T::Bits: std::fmt::Debug + PartialEq,
+{
+    let mut toggled = value;
+    inherent(&mut toggled);
+
+    assert_eq!(expected, toggled.bits(), "{:?}.toggle_all()", value);
+
+    let mut toggled = value;
+    Flags::toggle_all(&mut toggled);
+
+    assert_eq!(expected, toggled.bits(), "Flags::toggle_all({:?})", value);
+}
@@ -7,23 +7,42 @@ fn cases() {
     case(
         TestFlags::ABC | TestFlags::from_bits_retain(1 << 3),
         TestFlags::ABC,
+        TestFlags::truncate,
     );
 
-    case(TestZero::empty(), TestZero::empty());
+    case(TestZero::empty(), TestZero::empty(), TestZero::truncate);
 
-    case(TestZero::all(), TestZero::all());
+    case(TestZero::all(), TestZero::all(), TestZero::truncate);
 
     case(
         TestFlags::from_bits_retain(1 << 3) | TestFlags::all(),
         TestFlags::all(),
+        TestFlags::truncate,
+    );
+
+    case(
+        TestFlags::from_bits_retain(0xff),
+        TestFlags::from_bits_retain(0b111),
+        TestFlags::truncate,
+    );
+
+    case(
+        TestExternal::from_bits_retain(0xff),
+        TestExternal::from_bits_retain(0xff),
+        TestExternal::truncate,
     );
 }
 
 #[track_caller]
-fn case<T: Flags + std::fmt::Debug>(mut before: T, after: T)
-where
-    T: std::fmt::Debug + PartialEq + Copy,
-{
-    before.truncate();
-    assert_eq!(before, after, "{:?}.truncate()", before);
+fn case<T: Flags + std::fmt::Debug + PartialEq + Copy>(
+    mut before: T,
+    after: T,
+    inherent: impl FnOnce(&mut T),
+) {
+    let mut inherent_before = before;
+    inherent(&mut inherent_before);
+    assert_eq!(inherent_before, after, "{:?}.truncate()", before);
+
+    Flags::truncate(&mut before);
+    assert_eq!(before, after, "Flags::truncate({:?})", before);
 }
@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+
+use crate::UnknownBits;
+
+bitflags! {
+    #[bitflags(derive_try_from_bits)]
+    #[derive(Debug, PartialEq, Eq)]
+    struct TestTryFromBits: u32 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+    }
+}
+
+bitflags! {
+    #[bitflags(non_exhaustive, derive_try_from_bits)]
+    #[derive(Debug, PartialEq, Eq)]
+    struct TestTryFromBitsNonExhaustive: u8 {
+        const A = 1;
+    }
+}
+
+#[test]
+fn cases() {
+    assert_eq!(Ok(TestTryFromBits::empty()), TestTryFromBits::try_from(0));
+    assert_eq!(
+        Ok(TestTryFromBits::A | TestTryFromBits::C),
+        TestTryFromBits::try_from(1 | 1 << 2)
+    );
+
+    assert_eq!(
+        Err(UnknownBits::new(1 << 3, 1 << 3)),
+        TestTryFromBits::try_from(1 << 3)
+    );
+    assert_eq!(
+        Err(UnknownBits::new(1 | 1 << 8 | 1 << 31, 1 << 8 | 1 << 31)),
+        TestTryFromBits::try_from(1 | 1 << 8 | 1 << 31)
+    );
+
+    assert_eq!(
+        Ok(TestTryFromBitsNonExhaustive::from_bits_retain(!0)),
+        TestTryFromBitsNonExhaustive::try_from(!0)
+    );
+}
+
+#[test]
+fn error() {
+    let err = TestTryFromBits::try_from(1 | 1 << 4).unwrap_err();
+
+    assert_eq!(1 | 1 << 4, *err.bits());
+    assert_eq!(1 << 4, *err.unknown_bits());
+    assert_eq!("unknown bits `0x10` in `0x11`", err.to_string());
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+use crate::{Flags, UnknownBits};
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::A, Ok(1)),
+            (TestFlags::A | TestFlags::B, Ok(1 | 1 << 1)),
+            (TestFlags::empty(), Ok(0)),
+            (
+                TestFlags::from_bits_retain(1 << 3),
+                Err(UnknownBits::new(1 << 3, 1 << 3)),
+            ),
+            (
+                TestFlags::B | TestFlags::from_bits_retain(1 << 3),
+                Err(UnknownBits::new(1 << 1 | 1 << 3, 1 << 3)),
+            ),
+        ],
+    );
+
+    // Unknown bits already in the value are kept
+    case(
+        TestFlags::A | TestFlags::from_bits_retain(1 << 4),
+        &[
+            (TestFlags::C, Ok(1 | 1 << 2 | 1 << 4)),
+            (
+                TestFlags::from_bits_retain(1 << 4),
+                Err(UnknownBits::new(1 << 4, 1 << 4)),
+            ),
+        ],
+    );
+
+    case(
+        TestExternal::empty(),
+        &[(TestExternal::from_bits_retain(1 << 5), Ok(1 << 5))],
+    );
+
+    case(
+        TestZero::empty(),
+        &[(TestZero::from_bits_retain(1), Err(UnknownBits::new(1, 1)))],
+    );
+}
+
+// The expected bits after inserting, or the error if the input has unknown bits
+type Expected<B> = Result<B, UnknownBits<B>>;
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(value: T, inputs: &[(T, Expected<T::Bits>)])
+where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        let mut actual = value;
+        let result = actual.try_insert(*input);
+
+        match expected {
+            Ok(bits) => {
+                assert_eq!(Ok(()), result, "{:?}.try_insert({:?})", value, input);
+                assert_eq!(*bits, actual.bits(), "{:?}.try_insert({:?})", value, input);
+            }
+            Err(err) => {
+                assert_eq!(Err(*err), result, "{:?}.try_insert({:?})", value, input);
+
+                // The value is left unchanged
+                assert_eq!(
+                    value.bits(),
+                    actual.bits(),
+                    "{:?}.try_insert({:?})",
+                    value,
+                    input
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(&[], 0);
+    case(&[TestFlags::A], 1);
+    case(&[TestFlags::A, TestFlags::C], 1 | 1 << 2);
+    case(&[TestFlags::A, TestFlags::A | TestFlags::B], 1 | 1 << 1);
+    case(
+        &[TestFlags::A, TestFlags::from_bits_retain(1 << 3)],
+        1 | 1 << 3,
+    );
+
+    assert_eq!(
+        TestZero::empty(),
+        TestZero::union_all([TestZero::ZERO, TestZero::ZERO])
+    );
+}
+
+#[track_caller]
+fn case(inputs: &[TestFlags], expected: u8) {
+    assert_eq!(
+        expected,
+        TestFlags::union_all(inputs.iter().copied()).bits(),
+        "TestFlags::union_all({:?})",
+        inputs
+    );
+    assert_eq!(
+        expected,
+        inputs.iter().fold(TestFlags::empty(), |a, b| a | *b).bits(),
+        "{:?}.fold(|)",
+        inputs
+    );
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::empty(),
+        &[
+            (TestFlags::A, 1),
+            (TestFlags::all(), 1 | 1 << 1 | 1 << 2),
+            (TestFlags::empty(), 0),
+            (TestFlags::from_bits_retain(1 << 3), 1 << 3),
+        ],
+        TestFlags::with,
+        TestFlags::with_set,
+    );
+
+    case(
+        TestFlags::A | TestFlags::C,
+        &[
+            (TestFlags::A | TestFlags::B, 1 | 1 << 1 | 1 << 2),
+            (TestFlags::A, 1 | 1 << 2),
+        ],
+        TestFlags::with,
+        TestFlags::with_set,
+    );
+}
+
+#[test]
+fn chained() {
+    const FLAGS: TestFlags = TestFlags::empty()
+        .with(TestFlags::A)
+        .with(TestFlags::B)
+        .with_set(TestFlags::C, false);
+
+    assert_eq!(TestFlags::A | TestFlags::B, FLAGS);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, T::Bits)],
+    mut inherent: impl FnMut(T, T) -> T,
+    mut inherent_set: impl FnMut(T, T, bool) -> T,
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(value, *input).bits(),
+            "{:?}.with({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::with(value, *input).bits(),
+            "Flags::with({:?}, {:?})",
+            value,
+            input
+        );
+
+        assert_eq!(
+            *expected,
+            inherent_set(value, *input, true).bits(),
+            "{:?}.with_set({:?}, true)",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::with_set(value, *input, true).bits(),
+            "Flags::with_set({:?}, {:?}, true)",
+            value,
+            input
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+use crate::Flags;
+
+#[test]
+fn cases() {
+    case(
+        TestFlags::A | TestFlags::B,
+        &[
+            (TestFlags::A, 1 << 1),
+            (TestFlags::B, 1),
+            (TestFlags::all(), 0),
+            (TestFlags::empty(), 1 | 1 << 1),
+        ],
+        TestFlags::without,
+        TestFlags::with_set,
+    );
+
+    case(
+        TestFlags::from_bits_retain(1 | 1 << 3),
+        &[
+            (TestFlags::A, 1 << 3),
+            (TestFlags::from_bits_retain(1 << 3), 1),
+        ],
+        TestFlags::without,
+        TestFlags::with_set,
+    );
+}
+
+#[test]
+fn chained() {
+    const FLAGS: TestFlags = TestFlags::all()
+        .without(TestFlags::A)
+        .with_set(TestFlags::B, false);
+
+    assert_eq!(TestFlags::C, FLAGS);
+}
+
+#[track_caller]
+fn case<T: Flags + std::fmt::Debug + Copy>(
+    value: T,
+    inputs: &[(T, T::Bits)],
+    mut inherent: impl FnMut(T, T) -> T,
+    mut inherent_set: impl FnMut(T, T, bool) -> T,
+) where
+    T::Bits: std::fmt::Debug + PartialEq + Copy,
+{
+    for (input, expected) in inputs {
+        assert_eq!(
+            *expected,
+            inherent(value, *input).bits(),
+            "{:?}.without({:?})",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::without(value, *input).bits(),
+            "Flags::without({:?}, {:?})",
+            value,
+            input
+        );
+
+        assert_eq!(
+            *expected,
+            inherent_set(value, *input, false).bits(),
+            "{:?}.with_set({:?}, false)",
+            value,
+            input
+        );
+        assert_eq!(
+            *expected,
+            Flags::with_set(value, *input, false).bits(),
+            "Flags::with_set({:?}, {:?}, false)",
+            value,
+            input
+        );
+    }
+}
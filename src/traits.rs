@@ -4,12 +4,43 @@ use core::{
 };
 
 use crate::{
-    iter,
+    iter::{self, BitPositions},
     parser::{ParseError, ParseHex, WriteHex},
 };
 
 /**
 A defined flags value that may be named or unnamed.
+
+Each constant in a [`bitflags`](macro.bitflags.html) declaration produces one `Flag` in
+[`Flags::FLAGS`], in the order they're declared. Named flags are recognized when formatting
+and parsing. Unnamed flags, declared as `const _`, have an empty name. They're never formatted
+or parsed by name, but their bits are still considered known, so they affect methods like
+[`Flags::all`] and [`Flags::from_bits_truncate`].
+
+## Examples
+
+Walking the defined flags of a flags type:
+
+```
+use bitflags::{bitflags, Flags};
+
+bitflags! {
+    struct MyFlags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+
+        const _ = 1 << 7;
+    }
+}
+
+let named = MyFlags::FLAGS
+    .iter()
+    .filter(|flag| flag.is_named())
+    .map(|flag| flag.name())
+    .collect::<Vec<_>>();
+
+assert_eq!(vec!["A", "B"], named);
+```
 */
 #[derive(Debug)]
 pub struct Flag<B> {
@@ -62,6 +93,54 @@ impl<B> Flag<B> {
     }
 }
 
+/**
+An error converting bits into a flags value because they contain unknown bits.
+
+This error is returned by the `TryFrom` implementation generated by
+`#[bitflags(derive_try_from_bits)]`, and by [`Flags::try_insert`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownBits<B> {
+    bits: B,
+    unknown_bits: B,
+}
+
+impl<B> UnknownBits<B> {
+    /**
+    Create an error for `bits`, where `unknown_bits` are the bits that don't correspond to any flag.
+    */
+    pub const fn new(bits: B, unknown_bits: B) -> Self {
+        UnknownBits { bits, unknown_bits }
+    }
+
+    /**
+    Get the bits that failed to convert.
+    */
+    pub const fn bits(&self) -> &B {
+        &self.bits
+    }
+
+    /**
+    Get the bits that don't correspond to any flag.
+    */
+    pub const fn unknown_bits(&self) -> &B {
+        &self.unknown_bits
+    }
+}
+
+impl<B: fmt::LowerHex> fmt::Display for UnknownBits<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown bits `{:#x}` in `{:#x}`",
+            self.unknown_bits, self.bits
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: fmt::Debug + fmt::LowerHex> std::error::Error for UnknownBits<B> {}
+
 /**
 A set of defined flags using a bits type as storage.
 
@@ -152,6 +231,32 @@ pub trait Flags: Sized + 'static {
         Self::from_bits_retain(truncated)
     }
 
+    /// Get a flags value with the bits of all named flags set.
+    ///
+    /// This method is like [`Flags::all`], except unnamed flags are ignored.
+    /// For flags types that define an unnamed flag like `const _ = !0`, the
+    /// result is the union of only the named flags.
+    fn all_named() -> Self {
+        Self::from_bits_retain(Self::named_bits())
+    }
+
+    /// Get the bits of all named flags.
+    ///
+    /// This is the bits value of [`Flags::all_named`]. It's the same as `Self::all().bits()`
+    /// unless the flags type defines unnamed flags, like `const _ = !0`, whose bits are
+    /// known but excluded here. It can be used to mask a bits value to only its named flags.
+    fn named_bits() -> Self::Bits {
+        let mut named = Self::Bits::EMPTY;
+
+        for flag in Self::FLAGS.iter() {
+            if flag.is_named() {
+                named = named | flag.value().bits();
+            }
+        }
+
+        named
+    }
+
     /// This method will return `true` if any unknown bits are set.
     fn contains_unknown_bits(&self) -> bool {
         Self::all().bits() & self.bits() != self.bits()
@@ -162,6 +267,13 @@ pub trait Flags: Sized + 'static {
     /// The returned value is exactly the bits set in this flags value.
     fn bits(&self) -> Self::Bits;
 
+    /// Get the underlying bits value, consuming the flags value.
+    ///
+    /// This method is like [`Flags::bits`], but takes `self` by value.
+    fn into_bits(self) -> Self::Bits {
+        self.bits()
+    }
+
     /// Convert from a bits value.
     ///
     /// This method will return `None` if any unknown bits are set.
@@ -183,6 +295,15 @@ pub trait Flags: Sized + 'static {
     /// Convert from a bits value exactly.
     fn from_bits_retain(bits: Self::Bits) -> Self;
 
+    /// Convert from a bits value exactly, also returning any unknown bits.
+    ///
+    /// The returned flags value is the same as [`Flags::from_bits_retain`].
+    /// The returned bits value is the set of unknown bits, which will be empty if
+    /// [`Flags::from_bits`] would return `Some`.
+    fn from_bits_with_unknown(bits: Self::Bits) -> (Self, Self::Bits) {
+        (Self::from_bits_retain(bits), bits & !Self::all().bits())
+    }
+
     /// Get a flags value with the bits of a flag with the given name set.
     ///
     /// This method will return `None` if `name` is empty or doesn't
@@ -202,10 +323,59 @@ pub trait Flags: Sized + 'static {
         None
     }
 
+    /// Get a flags value with the bits of all flags with the given names set.
+    ///
+    /// Each name is resolved with [`Flags::from_name`], so only names of defined flags are
+    /// accepted. Unlike [`crate::parser::from_str`], hex numbers aren't parsed.
+    /// An empty set of names produces an empty flags value.
+    fn from_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Result<Self, ParseError> {
+        let mut parsed = Self::Bits::EMPTY;
+
+        for name in names {
+            if name.is_empty() {
+                return Err(ParseError::empty_flag());
+            }
+
+            match Self::from_name(name) {
+                Some(flag) => parsed = parsed | flag.bits(),
+                None => return Err(ParseError::invalid_named_flag(name)),
+            }
+        }
+
+        Ok(Self::from_bits_retain(parsed))
+    }
+
+    /// The bitwise or (`|`) of the bits in all the given flags values.
+    ///
+    /// An empty iterator produces an empty flags value. Unknown bits are retained.
+    fn union_all(iter: impl IntoIterator<Item = Self>) -> Self {
+        let mut union = Self::empty();
+
+        for flags in iter {
+            union.insert(flags);
+        }
+
+        union
+    }
+
+    /// The bitwise and (`&`) of the bits in all the given flags values.
+    ///
+    /// An empty iterator produces [`Flags::all`], since it's the identity of intersection.
+    /// Unknown bits are retained if every flags value contains them.
+    fn intersection_all(iter: impl IntoIterator<Item = Self>) -> Self {
+        let mut iter = iter.into_iter();
+
+        match iter.next() {
+            Some(first) => iter.fold(first, Flags::intersection),
+            None => Self::all(),
+        }
+    }
+
     /// Yield a set of contained flags values.
     ///
     /// Each yielded flags value will correspond to a defined named flag. Any unknown bits
     /// will be yielded together as a final flags value.
+    #[must_use]
     fn iter(&self) -> iter::Iter<Self> {
         iter::Iter::new(self)
     }
@@ -214,16 +384,200 @@ pub trait Flags: Sized + 'static {
     ///
     /// This method is like [`Flags::iter`], except only yields bits in contained named flags.
     /// Any unknown bits, or bits not corresponding to a contained flag will not be yielded.
+    #[must_use]
     fn iter_names(&self) -> iter::IterNames<Self> {
         iter::IterNames::new(self)
     }
 
+    /// Yield every named flag contained in the flags value.
+    ///
+    /// This method is like [`Flags::iter_names`], except flags are yielded even if their bits
+    /// are already covered by other flags. When flags overlap, this reports every flag
+    /// that matches the value, instead of just the flags needed to cover it.
+    fn matching_flags(&self) -> iter::MatchingFlags<Self> {
+        iter::MatchingFlags::new(self)
+    }
+
+    /// Yield the position of each set bit.
+    ///
+    /// This method is unlike [`Flags::iter`], because it yields individual bit indexes
+    /// in ascending order instead of flags values, regardless of how bits correspond to flags.
+    fn iter_bits(&self) -> iter::IterBits<Self>
+    where
+        Self::Bits: BitPositions,
+    {
+        iter::IterBits::new(self)
+    }
+
+    /// Yield the position of each set unknown bit.
+    ///
+    /// This method is like [`Flags::iter_bits`], except only bits that don't correspond
+    /// to any defined flag are yielded, in ascending order.
+    fn iter_unknown(&self) -> iter::IterBits<Self>
+    where
+        Self::Bits: BitPositions,
+    {
+        iter::IterBits::new(&Self::from_bits_retain(self.bits() & !Self::all().bits()))
+    }
+
+    /// Yield the names of all defined named flags.
+    ///
+    /// Names are yielded in the order their flags are defined in [`Flags::FLAGS`],
+    /// which is the same set of names recognized by [`Flags::from_name`].
+    fn names() -> iter::Names<Self> {
+        iter::Names::new()
+    }
+
+    /// The number of set bits, regardless of how they correspond to flags.
+    ///
+    /// This method is unlike [`Flags::count`], because it counts individual bits instead of
+    /// flags values. Bits shared by overlapping flags are only counted once, and unknown bits
+    /// are counted too.
+    fn bits_count(&self) -> u32
+    where
+        Self::Bits: BitPositions,
+    {
+        self.bits().count_ones()
+    }
+
+    /// The number of flags values that would be yielded by [`Flags::iter`].
+    ///
+    /// Multi-bit flags are counted once, and aren't counted at all if their bits
+    /// are already covered by previously defined flags. Any unknown bits, or bits not
+    /// corresponding to a contained flag, are counted together as a single final flags value.
+    fn count(&self) -> usize {
+        let source = self.bits();
+        let mut remaining = self.bits();
+        let mut count = 0;
+
+        for flag in Self::FLAGS {
+            // Short-circuit if our state is empty
+            if remaining == Self::Bits::EMPTY {
+                break;
+            }
+
+            // Skip unnamed flags
+            if flag.is_unnamed() {
+                continue;
+            }
+
+            // This follows the same logic as `Iter`
+            let bits = flag.value().bits();
+            if source & bits == bits && remaining & bits != Self::Bits::EMPTY {
+                remaining = remaining & !bits;
+                count += 1;
+            }
+        }
+
+        if remaining != Self::Bits::EMPTY {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Get the contained named flag with the greatest bits value.
+    ///
+    /// Flags are compared by their bits value as an unsigned number, not by how many bits they
+    /// set, so a multi-bit flag is greater than any of the flags it contains, and the sign bit of
+    /// a signed bits type is the greatest bit. Unnamed flags and flags with no bits set are
+    /// ignored, so this method returns `None` if no named flags are contained.
+    fn highest_set_flag(&self) -> Option<Self>
+    where
+        Self::Bits: BitPositions,
+    {
+        let mut highest: Option<Self> = None;
+
+        for flag in Self::FLAGS {
+            // Skip unnamed flags and flags with no bits set
+            if flag.is_unnamed() || flag.value().bits() == Self::Bits::EMPTY {
+                continue;
+            }
+
+            let bits = flag.value().bits();
+            if self.bits() & bits != bits {
+                continue;
+            }
+
+            match highest {
+                Some(ref current) if !unsigned_gt(bits, current.bits()) => {}
+                _ => highest = Some(Self::from_bits_retain(bits)),
+            }
+        }
+
+        highest
+    }
+
+    /// Get the contained named flag with the least bits value.
+    ///
+    /// Flags are compared the same way as in [`Flags::highest_set_flag`], so a multi-bit flag
+    /// is only the least contained flag if none of the flags it contains are defined.
+    fn lowest_set_flag(&self) -> Option<Self>
+    where
+        Self::Bits: BitPositions,
+    {
+        let mut lowest: Option<Self> = None;
+
+        for flag in Self::FLAGS {
+            // Skip unnamed flags and flags with no bits set
+            if flag.is_unnamed() || flag.value().bits() == Self::Bits::EMPTY {
+                continue;
+            }
+
+            let bits = flag.value().bits();
+            if self.bits() & bits != bits {
+                continue;
+            }
+
+            match lowest {
+                Some(ref current) if !unsigned_gt(current.bits(), bits) => {}
+                _ => lowest = Some(Self::from_bits_retain(bits)),
+            }
+        }
+
+        lowest
+    }
+
+    /// Get the only flags value that would be yielded by [`Flags::iter`].
+    ///
+    /// This method returns `None` if [`Flags::iter`] would yield no flags values, or more than one.
+    /// A multi-bit flag counts as a single flags value, but only if its bits aren't already covered
+    /// by previously defined flags. Any unknown bits, or bits not corresponding to a contained flag,
+    /// are yielded together as a single final flags value. A value containing only those bits
+    /// returns them, and a value containing a flag along with them returns `None`.
+    ///
+    /// Values with at most one bit set are handled by counting bits, without iterating over the
+    /// defined flags. Values with more bits set still iterate, because they may be a single
+    /// multi-bit flag, or only contain unknown bits.
+    fn exactly_one(&self) -> Option<Self>
+    where
+        Self::Bits: BitPositions,
+    {
+        // A single set bit is always yielded on its own, either as a flag or as an unknown bit
+        match self.bits_count() {
+            0 => return None,
+            1 => return Some(Self::from_bits_retain(self.bits())),
+            _ => (),
+        }
+
+        let mut iter = self.iter();
+
+        let flag = iter.next()?;
+        if iter.next().is_some() {
+            return None;
+        }
+
+        Some(flag)
+    }
+
     /// Whether all bits in this flags value are unset.
+    #[must_use]
     fn is_empty(&self) -> bool {
         self.bits() == Self::Bits::EMPTY
     }
 
     /// Whether all known bits in this flags value are set.
+    #[must_use]
     fn is_all(&self) -> bool {
         // NOTE: We check against `Self::all` here, not `Self::Bits::ALL`
         // because the set of all flags may not use all bits
@@ -231,6 +585,7 @@ pub trait Flags: Sized + 'static {
     }
 
     /// Whether any set bits in a source flags value are also set in a target flags value.
+    #[must_use]
     fn intersects(&self, other: Self) -> bool
     where
         Self: Sized,
@@ -239,6 +594,7 @@ pub trait Flags: Sized + 'static {
     }
 
     /// Whether all set bits in a source flags value are also set in a target flags value.
+    #[must_use]
     fn contains(&self, other: Self) -> bool
     where
         Self: Sized,
@@ -246,6 +602,36 @@ pub trait Flags: Sized + 'static {
         self.bits() & other.bits() == other.bits()
     }
 
+    /// Whether all set bits in a source flags value are also set in a target flags value.
+    ///
+    /// This method is equivalent to `other.contains(self)`.
+    fn is_subset(&self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.bits() & other.bits() == self.bits()
+    }
+
+    /// Whether all set bits in a target flags value are also set in a source flags value.
+    ///
+    /// This method is equivalent to `self.contains(other)`.
+    fn is_superset(&self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.contains(other)
+    }
+
+    /// Whether no set bits in a source flags value are also set in a target flags value.
+    ///
+    /// This method is equivalent to `!self.intersects(other)`.
+    fn is_disjoint(&self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        !self.intersects(other)
+    }
+
     /// Remove any unknown bits from the flags.
     fn truncate(&mut self)
     where
@@ -254,6 +640,14 @@ pub trait Flags: Sized + 'static {
         *self = Self::from_bits_truncate(self.bits());
     }
 
+    /// Unset all bits in the flags.
+    fn clear(&mut self)
+    where
+        Self: Sized,
+    {
+        *self = Self::empty();
+    }
+
     /// The bitwise or (`|`) of the bits in two flags values.
     fn insert(&mut self, other: Self)
     where
@@ -281,6 +675,16 @@ pub trait Flags: Sized + 'static {
         *self = Self::from_bits_retain(self.bits()).symmetric_difference(other);
     }
 
+    /// Flip every defined flag, removing any unknown bits.
+    ///
+    /// This method is like [`Flags::complement`], except it modifies the flags value in place.
+    fn toggle_all(&mut self)
+    where
+        Self: Sized,
+    {
+        *self = Self::from_bits_retain(self.bits()).complement();
+    }
+
     /// Call [`Flags::insert`] when `value` is `true` or [`Flags::remove`] when `value` is `false`.
     fn set(&mut self, other: Self, value: bool)
     where
@@ -293,6 +697,130 @@ pub trait Flags: Sized + 'static {
         }
     }
 
+    /// The bitwise or (`|`) of the bits in two flags values, ignoring any unknown bits in `other`.
+    ///
+    /// This method is like [`Flags::insert`], except only the bits of defined flags in `other`
+    /// are set. Any unknown bits already in `self` are kept.
+    fn insert_truncate(&mut self, other: Self)
+    where
+        Self: Sized,
+    {
+        self.insert(Self::from_bits_truncate(other.bits()));
+    }
+
+    /// The intersection of a source flags value with the complement of a target flags value (`&!`),
+    /// ignoring any unknown bits in `other`.
+    ///
+    /// This method is like [`Flags::remove`], except only the bits of defined flags in `other`
+    /// are unset. Any unknown bits in `self` are kept.
+    fn remove_truncate(&mut self, other: Self)
+    where
+        Self: Sized,
+    {
+        self.remove(Self::from_bits_truncate(other.bits()));
+    }
+
+    /// Call [`Flags::insert_truncate`] when `value` is `true` or [`Flags::remove_truncate`] when
+    /// `value` is `false`.
+    fn set_truncate(&mut self, other: Self, value: bool)
+    where
+        Self: Sized,
+    {
+        if value {
+            self.insert_truncate(other);
+        } else {
+            self.remove_truncate(other);
+        }
+    }
+
+    /// The bitwise or (`|`) of the bits in two flags values, failing if `other` has unknown bits.
+    ///
+    /// This method is like [`Flags::insert`], except if `other` contains any unknown bits then
+    /// `self` is left unchanged and an error with those bits is returned. Any unknown bits already
+    /// in `self` are kept.
+    fn try_insert(&mut self, other: Self) -> Result<(), UnknownBits<Self::Bits>>
+    where
+        Self: Sized,
+    {
+        if other.contains_unknown_bits() {
+            let bits = other.bits();
+
+            return Err(UnknownBits::new(bits, bits & !Self::all().bits()));
+        }
+
+        self.insert(other);
+
+        Ok(())
+    }
+
+    /// The bitwise or (`|`) of the bits in two flags values.
+    ///
+    /// This method returns `true` if any bits weren't already set.
+    fn insert_checked(&mut self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        let before = self.bits();
+        self.insert(other);
+
+        self.bits() != before
+    }
+
+    /// The intersection of a source flags value with the complement of a target flags value (`&!`).
+    ///
+    /// This method returns `true` if any bits were unset.
+    fn remove_checked(&mut self, other: Self) -> bool
+    where
+        Self: Sized,
+    {
+        let before = self.bits();
+        self.remove(other);
+
+        self.bits() != before
+    }
+
+    /// Call [`Flags::insert_checked`] when `value` is `true` or [`Flags::remove_checked`] when `value` is `false`.
+    ///
+    /// This method returns `true` if any bits were changed.
+    fn set_checked(&mut self, other: Self, value: bool) -> bool
+    where
+        Self: Sized,
+    {
+        if value {
+            self.insert_checked(other)
+        } else {
+            self.remove_checked(other)
+        }
+    }
+
+    /// Keep only the named flags in the flags value that satisfy a predicate.
+    ///
+    /// The predicate is called for each flag yielded by [`Flags::iter_names`], and the bits of
+    /// flags it returns `false` for are unset. Bits that don't correspond to a named flag,
+    /// like unknown bits, are kept.
+    ///
+    /// Flags that are kept are never changed, so if flags overlap, bits shared between a flag
+    /// that's kept and a flag that's removed stay set. The removed flag may still be contained
+    /// in the result if all of its bits are shared with flags that are kept.
+    fn retain(&mut self, mut f: impl FnMut(Self) -> bool)
+    where
+        Self: Sized,
+    {
+        let mut removed = Self::empty();
+        let mut kept = Self::empty();
+
+        for (_, flag) in self.iter_names() {
+            if f(Self::from_bits_retain(flag.bits())) {
+                kept.insert(flag);
+            } else {
+                removed.insert(flag);
+            }
+        }
+
+        self.remove(removed);
+        self.insert(kept);
+    }
+
     /// The bitwise and (`&`) of the bits in two flags values.
     #[must_use]
     fn intersection(self, other: Self) -> Self {
@@ -320,15 +848,96 @@ pub trait Flags: Sized + 'static {
         Self::from_bits_retain(self.bits() ^ other.bits())
     }
 
+    /// The bits that were added and removed going from an old flags value to a new one.
+    ///
+    /// `self` is the old flags value and `other` is the new one. The result is `(added, removed)`,
+    /// where `added` is `other.difference(self)` and `removed` is `self.difference(other)`.
+    /// Either half can be passed to [`Flags::iter_names`] to display it.
+    #[must_use]
+    fn added_removed(self, other: Self) -> (Self, Self) {
+        let added = Self::from_bits_retain(other.bits() & !self.bits());
+        let removed = Self::from_bits_retain(self.bits() & !other.bits());
+
+        (added, removed)
+    }
+
     /// The bitwise negation (`!`) of the bits in a flags value, truncating the result.
     #[must_use]
     fn complement(self) -> Self {
         Self::from_bits_truncate(!self.bits())
     }
+
+    /// The bitwise or (`|`) of the bits in two flags values.
+    ///
+    /// This method is like [`Flags::insert`], except it consumes and returns the flags value.
+    #[must_use]
+    fn with(self, other: Self) -> Self {
+        self.union(other)
+    }
+
+    /// The intersection of a source flags value with the complement of a target flags value (`&!`).
+    ///
+    /// This method is like [`Flags::remove`], except it consumes and returns the flags value.
+    #[must_use]
+    fn without(self, other: Self) -> Self {
+        self.difference(other)
+    }
+
+    /// Call [`Flags::with`] when `value` is `true` or [`Flags::without`] when `value` is `false`.
+    ///
+    /// This method is like [`Flags::set`], except it consumes and returns the flags value.
+    #[must_use]
+    fn with_set(self, other: Self, value: bool) -> Self {
+        if value {
+            self.with(other)
+        } else {
+            self.without(other)
+        }
+    }
+
+    /// Apply a function to the underlying bits value, keeping the flags type.
+    ///
+    /// The result is converted with [`Flags::from_bits_retain`], so any unknown bits
+    /// produced by `f` are retained.
+    #[must_use]
+    fn map_bits(self, f: impl FnOnce(Self::Bits) -> Self::Bits) -> Self {
+        Self::from_bits_retain(f(self.bits()))
+    }
+
+    /// Get this flags value with any unknown bits unset.
+    ///
+    /// This method is like [`Flags::truncate`], except it consumes and returns the flags value.
+    /// Equality on flags types compares their bits exactly, so values that only differ in unknown
+    /// bits aren't equal. Their canonical values are, which makes them suitable for
+    /// deduplicating or hashing by only the defined flags they contain.
+    #[must_use]
+    fn canonical(self) -> Self {
+        Self::from_bits_truncate(self.bits())
+    }
 }
 
 /**
 A bits type that can be used as storage for a flags type.
+
+Every bits type must have a [`Bits::EMPTY`] value, and be closed under the bitwise
+operators, so types like [`core::num::NonZeroU32`] that can't represent zero can't be
+used as bits types. A flags type always has an empty state.
+
+Implementations are expected to behave like a fixed-width unsigned integer:
+
+- `EMPTY` has no bits set and `ALL` has every bit set, so `!EMPTY == ALL`.
+- `&`, `|`, `^`, and `!` operate on each bit independently.
+- `==` compares every bit, so two values are equal if and only if they have the same bits set.
+
+Some functionality needs additional traits on the bits type:
+
+- [`ParseHex`](crate::parser::ParseHex) and [`WriteHex`](crate::parser::WriteHex) for parsing and
+  formatting flags values as text. The docs on `ParseHex` show implementing them.
+- [`BitPositions`](crate::iter::BitPositions) for iterating over the positions of set bits.
+
+The [`bitflags`](crate::bitflags) macro only accepts primitive integer bits types, but any bits type
+can be used with a manual implementation of [`Flags`]. With the `ethnum` feature, `ethnum::U256`
+implements all of these traits.
 */
 pub trait Bits:
     Clone
@@ -348,6 +957,73 @@ pub trait Bits:
     const ALL: Self;
 }
 
+// The number of hex digits needed for the largest primitive bits type
+const HEX_DIGITS: usize = 32;
+
+// Strip any `_` digit separators from a hex number, like `dead_beef`
+//
+// Leading zeros are also stripped, so the digits fit in a buffer sized for the
+// largest bits type. Inputs without separators are returned unchanged.
+pub(crate) fn strip_hex_separators<'a, const N: usize>(
+    input: &'a str,
+    buf: &'a mut [u8; N],
+) -> Option<&'a str> {
+    if !input.contains('_') {
+        return Some(input);
+    }
+
+    let mut len = 0;
+
+    for digit in hex_digits(input)? {
+        *buf.get_mut(len)? = digit;
+        len += 1;
+    }
+
+    if len == 0 {
+        return Some("0");
+    }
+
+    core::str::from_utf8(&buf[..len]).ok()
+}
+
+// The significant digits of a hex number, skipping any `_` digit separators and leading zeros
+//
+// This returns `None` if the number has no digits, or if it has separators along with
+// anything other than hex digits.
+fn hex_digits(input: &str) -> Option<impl Iterator<Item = u8> + Clone + '_> {
+    let digits = input.bytes().filter(|digit| *digit != b'_');
+
+    // A number made of only separators is invalid
+    digits.clone().next()?;
+
+    // Signs aren't allowed along with separators, like in Rust literals
+    if input.contains('_') && !digits.clone().all(|digit| digit.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(digits.skip_while(|digit| *digit == b'0'))
+}
+
+// Whether `a` is greater than `b` when both are compared as unsigned numbers
+//
+// The greater value is the one that has the highest bit that isn't set in both
+fn unsigned_gt<B: BitPositions>(a: B, b: B) -> bool {
+    let diff = a ^ b;
+
+    highest_bit(a & diff) > highest_bit(b & diff)
+}
+
+// The position of the highest set bit, or `None` if no bits are set
+fn highest_bit<B: BitPositions>(mut bits: B) -> Option<u32> {
+    let mut highest = None;
+
+    while let Some(bit) = bits.take_lowest_bit() {
+        highest = Some(bit);
+    }
+
+    highest
+}
+
 // Not re-exported: prevent custom `Bits` impls being used in the `bitflags!` macro,
 // or they may fail to compile based on crate features
 pub trait Primitive {}
@@ -367,13 +1043,71 @@ macro_rules! impl_bits {
 
             impl ParseHex for $u {
                 fn parse_hex(input: &str) -> Result<Self, ParseError> {
-                    <$u>::from_str_radix(input, 16).map_err(|_| ParseError::invalid_hex_flag(input))
+                    let mut buf = [0; HEX_DIGITS];
+                    let digits = strip_hex_separators(input, &mut buf)
+                        .ok_or_else(|| ParseError::invalid_hex_flag(input))?;
+
+                    <$u>::from_str_radix(digits, 16).map_err(|_| ParseError::invalid_hex_flag(input))
+                }
+
+                fn parse_bin(input: &str) -> Result<Self, ParseError> {
+                    <$u>::from_str_radix(input, 2).map_err(|_| ParseError::invalid_bin_flag(input))
+                }
+
+                fn parse_oct(input: &str) -> Result<Self, ParseError> {
+                    <$u>::from_str_radix(input, 8).map_err(|_| ParseError::invalid_oct_flag(input))
                 }
             }
 
             impl ParseHex for $i {
                 fn parse_hex(input: &str) -> Result<Self, ParseError> {
-                    <$i>::from_str_radix(input, 16).map_err(|_| ParseError::invalid_hex_flag(input))
+                    let mut buf = [0; HEX_DIGITS];
+                    let digits = strip_hex_separators(input, &mut buf)
+                        .ok_or_else(|| ParseError::invalid_hex_flag(input))?;
+
+                    <$i>::from_str_radix(digits, 16).map_err(|_| ParseError::invalid_hex_flag(input))
+                }
+
+                fn parse_bin(input: &str) -> Result<Self, ParseError> {
+                    <$i>::from_str_radix(input, 2).map_err(|_| ParseError::invalid_bin_flag(input))
+                }
+
+                fn parse_oct(input: &str) -> Result<Self, ParseError> {
+                    <$i>::from_str_radix(input, 8).map_err(|_| ParseError::invalid_oct_flag(input))
+                }
+            }
+
+            impl BitPositions for $u {
+                fn take_lowest_bit(&mut self) -> Option<u32> {
+                    if *self == 0 {
+                        return None;
+                    }
+
+                    let bit = self.trailing_zeros();
+                    *self &= !(1 << bit);
+
+                    Some(bit)
+                }
+
+                fn count_ones(&self) -> u32 {
+                    <$u>::count_ones(*self)
+                }
+            }
+
+            impl BitPositions for $i {
+                fn take_lowest_bit(&mut self) -> Option<u32> {
+                    if *self == 0 {
+                        return None;
+                    }
+
+                    let bit = self.trailing_zeros();
+                    *self &= !(1 << bit);
+
+                    Some(bit)
+                }
+
+                fn count_ones(&self) -> u32 {
+                    <$i>::count_ones(*self)
                 }
             }
 
@@ -404,6 +1138,56 @@ impl_bits! {
     usize, isize,
 }
 
+// Byte arrays can't implement `Bits` because the bitwise operators can't be implemented
+// for them here, but they can be used as the storage for a custom bits type that does.
+//
+// The hex representation of a byte array is big-endian: the first byte is the most
+// significant, so `[0x01, 0x00]` is formatted as `100`.
+
+impl<const N: usize> ParseHex for [u8; N] {
+    fn parse_hex(input: &str) -> Result<Self, ParseError> {
+        // Any `_` digit separators and leading zeros are skipped
+        let digits = hex_digits(input).ok_or_else(|| ParseError::invalid_hex_flag(input))?;
+        let len = digits.clone().count();
+
+        if len > N * 2 {
+            return Err(ParseError::invalid_hex_flag(input));
+        }
+
+        let mut parsed = [0; N];
+
+        for (i, digit) in digits.enumerate() {
+            let nibble = match (digit as char).to_digit(16) {
+                Some(nibble) => nibble as u8,
+                None => return Err(ParseError::invalid_hex_flag(input)),
+            };
+
+            // Digits are most significant first, so count their position from the end
+            let i = len - 1 - i;
+            parsed[N - 1 - i / 2] |= nibble << ((i % 2) * 4);
+        }
+
+        Ok(parsed)
+    }
+}
+
+impl<const N: usize> WriteHex for [u8; N] {
+    fn write_hex<W: fmt::Write>(&self, mut writer: W) -> fmt::Result {
+        let mut bytes = self.iter().skip_while(|b| **b == 0);
+
+        match bytes.next() {
+            Some(first) => write!(writer, "{:x}", first)?,
+            None => return writer.write_str("0"),
+        }
+
+        for byte in bytes {
+            write!(writer, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// A trait for referencing the `bitflags`-owned internal type
 /// without exposing it publicly.
 pub trait PublicFlags {
@@ -441,4 +1225,25 @@ pub trait ImplementedByBitFlagsMacro {}
 
 pub(crate) mod __private {
     pub use super::{ImplementedByBitFlagsMacro, PublicFlags};
+
+    // Compare two strings in a `const` context, where `==` isn't available
+    pub const fn str_eq(a: &str, b: &str) -> bool {
+        let a = a.as_bytes();
+        let b = b.as_bytes();
+
+        if a.len() != b.len() {
+            return false;
+        }
+
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        true
+    }
 }
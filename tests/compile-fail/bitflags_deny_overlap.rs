@@ -0,0 +1,12 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(deny_overlap)]
+    pub struct Flags: u32 {
+        const CLIENT_A = 0x1000;
+        const CLIENT_B = 0x2000;
+        const CLIENT_RESERVED = 0x2000;
+    }
+}
+
+fn main() {}
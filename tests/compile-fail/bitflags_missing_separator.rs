@@ -0,0 +1,10 @@
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Flags1: u32 {
+        const A = 1
+        const B = 1 << 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(bogus)]
+    pub struct Unknown: u32 {
+        const A = 1;
+    }
+}
+
+bitflags! {
+    #[bitflags(debug = "bogus")]
+    pub struct UnknownDebug: u32 {
+        const A = 1;
+    }
+}
+
+fn main() {}
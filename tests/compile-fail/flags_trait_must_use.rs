@@ -0,0 +1,19 @@
+#![deny(unused_must_use)]
+
+use bitflags::{bitflags, Flags};
+
+bitflags! {
+    #[derive(Clone, Copy)]
+    struct MyFlags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    let flags = MyFlags::A;
+
+    Flags::union(flags, MyFlags::B);
+    Flags::contains(&flags, MyFlags::B);
+    Flags::iter(&flags);
+}
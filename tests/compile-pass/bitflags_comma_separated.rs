@@ -0,0 +1,42 @@
+use bitflags::bitflags;
+
+bitflags! {
+    pub struct Comma: u8 {
+        const A = 1,
+        const B = 1 << 1,
+    }
+}
+
+bitflags! {
+    pub struct Mixed: u8 {
+        /// A flag terminated by `;`
+        const A = 1;
+        /// A flag separated by `,`
+        const B = 1 << 1,
+        #[cfg(all())]
+        const C = 1 << 2,
+        const ABC = Self::A.bits() | Self::B.bits() | Self::C.bits();
+    }
+
+    pub struct Trailing: u8 {
+        const A = 1,
+        const _ = !0,
+    }
+}
+
+pub struct External(u8);
+
+bitflags! {
+    impl External: u8 {
+        const A = 1,
+        const B = 1 << 1;
+    }
+}
+
+fn main() {
+    assert_eq!(0b11, (Comma::A | Comma::B).bits());
+    assert_eq!(0b111, Mixed::ABC.bits());
+    assert_eq!(0b111, Mixed::all().bits());
+    assert_eq!(!0, Trailing::from_bits_truncate(!0).bits());
+    assert_eq!(0b11, External::all().bits());
+}
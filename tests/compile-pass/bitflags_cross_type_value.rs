@@ -0,0 +1,30 @@
+use bitflags::bitflags;
+
+mod protocol {
+    pub const EXTENDED: u8 = 1 << 6;
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Flags1: u8 {
+        const A = 0b0001;
+        const B = 0b0010;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Flags2: u8 {
+        const A = Flags1::A.bits() | 0b1000;
+        const AB = Self::A.bits() | Flags1::B.bits();
+        const EXTENDED = protocol::EXTENDED;
+        const ALL_1 = Flags1::all().bits();
+    }
+}
+
+fn main() {
+    assert_eq!(0b1001, Flags2::A.bits());
+    assert_eq!(0b1011, Flags2::AB.bits());
+    assert_eq!(1 << 6, Flags2::EXTENDED.bits());
+    assert_eq!(0b0011, Flags2::ALL_1.bits());
+}
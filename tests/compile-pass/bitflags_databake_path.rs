@@ -0,0 +1,22 @@
+use bitflags::bitflags;
+
+// The attribute is accepted whether or not the `databake` feature is enabled
+bitflags! {
+    #[bitflags(databake_path = "my_crate::flags")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Flags: u32 {
+        const A = 1;
+        const B = 1 << 1;
+    }
+
+    #[bitflags(derive_display, databake_path = "my_crate::flags", inline = "never")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Combined: u8 {
+        const A = 1;
+    }
+}
+
+fn main() {
+    assert_eq!("Flags(A | B)", format!("{:?}", Flags::A | Flags::B));
+    assert_eq!("A", Combined::A.to_string());
+}
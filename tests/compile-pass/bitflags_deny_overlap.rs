@@ -0,0 +1,28 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[bitflags(deny_overlap)]
+    pub struct Flags: u32 {
+        const A = 0b0000_0001;
+        const B = 0b0000_0010;
+        #[cfg(any())]
+        const C = 0b0000_0010;
+        const C = 0b0000_0100;
+
+        // Multi-bit flags may overlap with other flags
+        const AB = Self::A.bits() | Self::B.bits();
+        const ABC = 0b0000_0111;
+
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    #[bitflags(non_exhaustive, deny_overlap)]
+    pub struct Empty: u8 {}
+}
+
+fn main() {
+    assert_eq!(0b0000_0111, Flags::ABC.bits());
+}
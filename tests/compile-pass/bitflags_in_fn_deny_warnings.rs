@@ -0,0 +1,18 @@
+#![deny(warnings)]
+
+use bitflags::bitflags;
+
+fn flags() -> u32 {
+    bitflags! {
+        struct Flags: u32 {
+            const A = 1;
+            const B = 1 << 1;
+        }
+    }
+
+    Flags::A.bits()
+}
+
+fn main() {
+    assert_eq!(1, flags());
+}
@@ -0,0 +1,26 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[bitflags(inline = "always")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Flags: u32 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+    }
+
+    #[bitflags(derive_display, inline = "always", non_exhaustive)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Combined: u8 {
+        const A = 1;
+    }
+}
+
+fn main() {
+    assert_eq!(Flags::A | Flags::C, Flags::from_bits_truncate(0b1101));
+    assert_eq!(Some(Flags::B), Flags::from_name("B"));
+    assert_eq!(2, Flags::all().difference(Flags::B).iter().count());
+    assert_eq!("Flags(A | B)", format!("{:?}", Flags::A | Flags::B));
+
+    assert_eq!("A | 0x2", Combined::from_bits_truncate(0b11).to_string());
+}
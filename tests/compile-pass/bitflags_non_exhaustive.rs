@@ -0,0 +1,26 @@
+mod a {
+    use bitflags::bitflags;
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub struct Flags: u32 {
+            const A = 0b00000001;
+            const B = 0b00000010;
+        }
+    }
+
+    impl Flags {
+        pub fn ab() -> Flags {
+            Flags::A | Flags::B
+        }
+    }
+}
+
+fn main() {
+    let flags = a::Flags::from_bits_retain(0b00000011);
+
+    assert_eq!(a::Flags::ab(), flags);
+    assert_eq!(0b00000011, flags.bits());
+    assert!(flags.contains(a::Flags::A));
+}
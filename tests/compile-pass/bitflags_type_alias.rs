@@ -0,0 +1,62 @@
+use std::convert::TryFrom;
+
+use bitflags::bitflags;
+
+type RegBits = u32;
+
+mod bits {
+    pub type Signed = i16;
+}
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(derive_display, derive_from_str, derive_try_from_bits, bool_array, deny_overlap)]
+    struct Reg: RegBits {
+        const A = 1;
+        const B = 1 << 1;
+        const AB = Self::A.bits() | Self::B.bits();
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    #[bitflags(non_exhaustive)]
+    struct Signed: bits::Signed {
+        const A = 1;
+        const SIGN = bits::Signed::MIN;
+    }
+}
+
+struct External(RegBits);
+
+bitflags! {
+    impl External: RegBits {
+        const A = 1;
+        const _ = !0;
+    }
+}
+
+const ALL: Reg = Reg::all();
+const FROM_BITS: Option<Reg> = Reg::from_bits(1);
+
+fn main() {
+    assert_eq!(0b11, ALL.bits());
+    assert_eq!(Some(Reg::A), FROM_BITS);
+    assert_eq!(None, Reg::from_bits(1 << 2));
+    assert!(Reg::try_from(1 << 2).is_err());
+    assert_eq!([true, false, false], Reg::A.as_bools());
+
+    assert_eq!(Reg::A | Reg::B, "A | B".parse::<Reg>().unwrap());
+    assert_eq!(
+        Reg::AB,
+        bitflags::parser::from_str::<Reg>("0x3").unwrap()
+    );
+    assert_eq!("A | B", Reg::AB.to_string());
+
+    assert!(Signed::covers_all_bits());
+    assert_eq!(
+        Signed::SIGN,
+        bitflags::parser::from_str::<Signed>("SIGN").unwrap()
+    );
+
+    assert_eq!(!0, External::all().0);
+    assert!(External::from_bits(!0).is_some());
+}
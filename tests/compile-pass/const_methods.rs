@@ -0,0 +1,68 @@
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Flags: u8 {
+        const A = 1;
+        const B = 1 << 1;
+        const C = 1 << 2;
+    }
+}
+
+const EMPTY: Flags = Flags::empty();
+const ALL: Flags = Flags::all();
+const BITS: u8 = Flags::A.bits();
+
+const FROM_BITS: Option<Flags> = Flags::from_bits(0b011);
+const FROM_BITS_TRUNCATE: Flags = Flags::from_bits_truncate(0b1001);
+const FROM_BITS_RETAIN: Flags = Flags::from_bits_retain(0b1001);
+const FROM_BITS_WITH_UNKNOWN: (Flags, u8) = Flags::from_bits_with_unknown(0b1001);
+const FROM_NAME: Option<Flags> = Flags::from_name("B");
+
+const IS_EMPTY: bool = EMPTY.is_empty();
+const IS_ALL: bool = ALL.is_all();
+const INTERSECTS: bool = ALL.intersects(Flags::A);
+const CONTAINS: bool = ALL.contains(Flags::B);
+const IS_SUBSET: bool = Flags::A.is_subset(ALL);
+const IS_SUPERSET: bool = ALL.is_superset(Flags::A);
+const IS_DISJOINT: bool = Flags::A.is_disjoint(Flags::B);
+
+const INTERSECTION: Flags = ALL.intersection(Flags::A);
+const UNION: Flags = Flags::A.union(Flags::B);
+const DIFFERENCE: Flags = ALL.difference(Flags::A);
+const SYMMETRIC_DIFFERENCE: Flags = Flags::A.symmetric_difference(ALL);
+const COMPLEMENT: Flags = Flags::A.complement();
+const COMPLEMENT_RETAIN: Flags = Flags::A.complement_retain();
+const WITH: Flags = Flags::A.with(Flags::C);
+const WITHOUT: Flags = ALL.without(Flags::C);
+const WITH_SET: Flags = Flags::A.with_set(Flags::B, true);
+
+fn main() {
+    assert_eq!(0, EMPTY.bits());
+    assert_eq!(0b111, ALL.bits());
+    assert_eq!(1, BITS);
+
+    assert_eq!(Some(Flags::A | Flags::B), FROM_BITS);
+    assert_eq!(Flags::A, FROM_BITS_TRUNCATE);
+    assert_eq!(0b1001, FROM_BITS_RETAIN.bits());
+    assert_eq!((FROM_BITS_RETAIN, 0b1000), FROM_BITS_WITH_UNKNOWN);
+    assert_eq!(Some(Flags::B), FROM_NAME);
+
+    assert!(IS_EMPTY);
+    assert!(IS_ALL);
+    assert!(INTERSECTS);
+    assert!(CONTAINS);
+    assert!(IS_SUBSET);
+    assert!(IS_SUPERSET);
+    assert!(IS_DISJOINT);
+
+    assert_eq!(Flags::A, INTERSECTION);
+    assert_eq!(Flags::A | Flags::B, UNION);
+    assert_eq!(Flags::B | Flags::C, DIFFERENCE);
+    assert_eq!(Flags::B | Flags::C, SYMMETRIC_DIFFERENCE);
+    assert_eq!(Flags::B | Flags::C, COMPLEMENT);
+    assert_eq!(0b1111_1110, COMPLEMENT_RETAIN.bits());
+    assert_eq!(Flags::A | Flags::C, WITH);
+    assert_eq!(Flags::A | Flags::B, WITHOUT);
+    assert_eq!(Flags::A | Flags::B, WITH_SET);
+}